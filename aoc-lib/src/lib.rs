@@ -1,8 +1,15 @@
 // aoc-lib/src/lib.rs
 
+pub mod puzzle_id;
+pub mod solution;
 pub mod utils;
+#[rustfmt::skip]
 pub mod year2024;
+#[rustfmt::skip]
 pub mod year2025;
 
+#[rustfmt::skip]
 mod registry_generated;
+pub use puzzle_id::PuzzleId;
 pub use registry_generated::SolutionRegistry;
+pub use solution::{DaySolver, PartSolver};
@@ -0,0 +1,150 @@
+// aoc-lib/src/puzzle_id.rs
+
+use crate::utils::calendar::{
+    civil_from_days, day_count, ensure_valid_day, ensure_valid_year,
+    format_utc, full_year, unix_now, unlock_time,
+};
+use crate::utils::output::format_remaining;
+use anyhow::{Context, Result};
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+// Puzzles unlock at midnight EST, so "today" is the date in UTC-5
+const EST_OFFSET_SECS: u64 = 5 * 3600;
+
+/// A validated puzzle: an Advent of Code year and one of its days.
+///
+/// Parses from `2024 5`, `2024/5`, `2024-05`, `24d5`, the canonical
+/// `year2024/day05` it displays as, or `today`. Only built through these,
+/// so every value names a real puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PuzzleId {
+    year: u16,
+    day: u8,
+}
+
+impl PuzzleId {
+    /// Fail unless `year` is an AoC year and `day` one of its puzzle days
+    pub fn new(year: u16, day: u8) -> Result<Self> {
+        ensure_valid_year(year)?;
+        ensure_valid_day(year, day)?;
+        Ok(Self { year, day })
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// The puzzle released most recently, if an event is running
+    pub fn today() -> Result<Self> {
+        Self::live_at(unix_now())
+    }
+
+    /// The puzzle released most recently at `unix_secs`; fails outside
+    /// December and after the event's last day, saying when the next
+    /// event starts
+    pub fn live_at(unix_secs: u64) -> Result<Self> {
+        let days = (unix_secs.saturating_sub(EST_OFFSET_SECS) / 86_400) as i64;
+        let (year, month, day) = civil_from_days(days);
+        let date = format!("{:04}-{:02}-{:02}", year, month, day);
+        let year = u16::try_from(year).context("year out of range")?;
+
+        if month != 12 {
+            anyhow::bail!(
+                "No puzzle is live today ({} EST): events run in December.\n{}",
+                date,
+                next_event(year, unix_secs)
+            );
+        }
+        let count = day_count(year);
+        if day > u32::from(count) {
+            anyhow::bail!(
+                "No puzzle is live today ({} EST): the {} event ended on December {}.\n{}",
+                date,
+                year,
+                count,
+                next_event(year + 1, unix_secs)
+            );
+        }
+        Self::new(year, day as u8)
+    }
+
+    /// Parse a puzzle from the start of command-line args: two args for
+    /// `<year> <day>`, else one (`2024/5`, `today`, ...). Returns the
+    /// puzzle and the args after it.
+    pub fn from_args(args: &[String]) -> Result<(Self, &[String])> {
+        let Some(first) = args.first() else {
+            anyhow::bail!(
+                "Missing puzzle: expected <year> <day>, 2024/5 or today"
+            );
+        };
+        let taken = if is_number(first) && args.len() > 1 {
+            2
+        } else {
+            1
+        };
+        let id = args[..taken].join(" ").parse()?;
+        Ok((id, &args[taken..]))
+    }
+}
+
+impl FromStr for PuzzleId {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim().to_ascii_lowercase();
+        if s == "today" {
+            return Self::today();
+        }
+
+        let invalid = || {
+            anyhow::anyhow!(
+                "Invalid puzzle {:?}: expected \"2024 5\", \"2024/5\", \"2024-05\", \"24d5\" or \"today\"",
+                s
+            )
+        };
+        let rest = s.strip_prefix("year").unwrap_or(&s);
+        let (year, day) = rest
+            .split_once(|c: char| {
+                c.is_whitespace() || matches!(c, '/' | '-' | 'd')
+            })
+            .ok_or_else(invalid)?;
+        let day = day.trim();
+        let day = day.strip_prefix("day").unwrap_or(day);
+        if !is_number(year) || !is_number(day) {
+            return Err(invalid());
+        }
+
+        let year: u16 = year.parse().map_err(|_| invalid())?;
+        let day: u8 = day.parse().map_err(|_| invalid())?;
+        Self::new(full_year(year), day)
+    }
+}
+
+impl fmt::Display for PuzzleId {
+    /// Canonical `year2024/day05`, matching the solution and input paths
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "year{}/day{:02}", self.year, self.day)
+    }
+}
+
+// "The 2025 event starts at 2025-12-01 05:00:00 UTC (midnight EST), in ..."
+fn next_event(year: u16, now: u64) -> String {
+    let start = unlock_time(year, 1);
+    format!(
+        "The {} event starts at {} (midnight EST), in {}",
+        year,
+        format_utc(start),
+        format_remaining(Duration::from_secs(start.saturating_sub(now)))
+    )
+}
+
+// Whether `s` is a non-empty run of ASCII digits
+fn is_number(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit())
+}
@@ -1,45 +1,75 @@
 // AUTO-GENERATED - DO NOT EDIT MANUALLY
 // Regenerate with: cargo run --bin registry-tool
 
-use anyhow::Result;
+use crate::solution::{DayEntry, DaySolver, PartSolver};
 
 // Import all detected year modules
 use crate::year2024;
 use crate::year2025;
 
-// Type alias for day registry entries
-type DayEntry = (&'static str, fn() -> Result<()>);
-
 pub struct SolutionRegistry;
 
-// Helper: convert DAYS entries like ("01", solver) to Vec<u8>
+// Helper: convert DAYS entries like ("01", TITLE, part1, part2) to Vec<u8>
 fn days_to_u8(days: &[DayEntry]) -> Vec<u8> {
-    days.iter().filter_map(|(d, _)| d.parse::<u8>().ok()).collect()
+    days.iter().filter_map(|(d, _, _, _)| d.parse::<u8>().ok()).collect()
+}
+
+// Helper: find both part solvers for a given day in a year's DAYS
+fn find_solver(days: &[DayEntry], day: u8) -> Option<DaySolver> {
+    let day_str = day.to_string();
+    days.iter()
+        .find(|(d, _, _, _)| *d == day_str)
+        .map(|(_, _, part1, part2)| DaySolver { part1: *part1, part2: *part2 })
 }
 
-// Helper: find solver for a given day in a year's DAYS
-fn find_solver(days: &[DayEntry], day: u8) -> Option<fn() -> Result<()>> {
+// Helper: find the puzzle title for a given day in a year's DAYS
+fn find_title(days: &[DayEntry], day: u8) -> Option<&'static str> {
     let day_str = day.to_string();
-    days.iter().find(|(d, _)| *d == day_str).map(|(_, s)| *s)
+    days.iter()
+        .find(|(d, _, _, _)| *d == day_str)
+        .map(|(_, title, _, _)| *title)
+        .filter(|title| !title.is_empty())
 }
 
 impl SolutionRegistry {
-    pub fn get_solver(year: u16, day: u8) -> Option<fn() -> Result<()>> {
+    pub fn get_solver(year: u16, day: u8) -> Option<DaySolver> {
         match year {
-            2024 => find_solver(&year2024::DAYS, day),
-            2025 => find_solver(&year2025::DAYS, day),
+            2024 => find_solver(year2024::DAYS, day),
+            2025 => find_solver(year2025::DAYS, day),
             _ => None,
         }
     }
 
+    pub fn get_part(year: u16, day: u8, part: u8) -> Option<PartSolver> {
+        Self::get_solver(year, day)?.part(part)
+    }
+
+    /// Puzzle title from the day's TITLE; None if unregistered or untitled
+    pub fn title(year: u16, day: u8) -> Option<&'static str> {
+        match year {
+            2024 => find_title(year2024::DAYS, day),
+            2025 => find_title(year2025::DAYS, day),
+            _ => None,
+        }
+    }
+
+    /// Puzzles in a year's event: its DAY_COUNT, else the schedule's default
+    pub fn day_count(year: u16) -> u8 {
+        match year {
+            2024 => year2024::DAY_COUNT,
+            2025 => year2025::DAY_COUNT,
+            _ => crate::utils::calendar::default_day_count(year),
+        }
+    }
+
     pub fn available_years() -> Vec<u16> {
         vec![2024, 2025]
     }
 
     pub fn available_days(year: u16) -> Vec<u8> {
         match year {
-            2024 => days_to_u8(&year2024::DAYS),
-            2025 => days_to_u8(&year2025::DAYS),
+            2024 => days_to_u8(year2024::DAYS),
+            2025 => days_to_u8(year2025::DAYS),
             _ => vec![],
         }
     }
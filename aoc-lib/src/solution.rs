@@ -0,0 +1,29 @@
+// aoc-lib/src/solution.rs
+
+use anyhow::Result;
+
+/// Signature of every registered part: puzzle input in, answer out.
+/// The caller decides whether to print, time, compare or store the answer.
+pub type PartSolver = fn(&str) -> Result<String>;
+
+/// Entry in a year's `DAYS` table:
+/// ("1", day01::TITLE, day01::part1, day01::part2)
+pub type DayEntry = (&'static str, &'static str, PartSolver, PartSolver);
+
+/// Both registered parts of a day
+#[derive(Clone, Copy)]
+pub struct DaySolver {
+    pub part1: PartSolver,
+    pub part2: PartSolver,
+}
+
+impl DaySolver {
+    /// Select a single part (1 or 2)
+    pub fn part(&self, part: u8) -> Option<PartSolver> {
+        match part {
+            1 => Some(self.part1),
+            2 => Some(self.part2),
+            _ => None,
+        }
+    }
+}
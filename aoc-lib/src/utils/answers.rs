@@ -0,0 +1,185 @@
+// `aoc-lib/src/utils/answers.rs`
+
+use crate::utils::cache::write_atomic;
+use crate::utils::load_input;
+use crate::utils::paths::answers_root;
+use crate::utils::session::profile_dir;
+use crate::SolutionRegistry;
+use anyhow::{anyhow, Context, Result};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+// Get the path to a year's accepted answers: `answers/yearYYYY.toml` in the
+// workspace root (`answers/<profile>/yearYYYY.toml` for named profiles)
+pub fn get_answers_path(year: u16) -> Result<PathBuf> {
+    Ok(profile_dir(&answers_root())?.join(format!("year{}.toml", year)))
+}
+
+/// Accepted answers for one year, keyed by day and part.
+///
+/// Stored as `answers/yearYYYY.toml`:
+///
+/// ```toml
+/// [day01]
+/// part1 = "1234"
+/// part2 = "5678"
+/// ```
+#[derive(Debug)]
+pub struct AnswerStore {
+    year: u16,
+    path: PathBuf,
+    days: BTreeMap<u8, [Option<String>; 2]>,
+}
+
+impl AnswerStore {
+    /// Load a year's answers; a missing file is an empty store
+    pub fn load(year: u16) -> Result<Self> {
+        Self::load_from(year, &get_answers_path(year)?)
+    }
+
+    /// Load a year's answers from `path`, which `save` then writes back to
+    pub fn load_from(year: u16, path: &Path) -> Result<Self> {
+        let mut store = Self {
+            year,
+            path: path.to_path_buf(),
+            days: BTreeMap::new(),
+        };
+
+        if !path.exists() {
+            return Ok(store);
+        }
+
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let table: toml::Table = content
+            .parse()
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+
+        for (key, value) in table {
+            let day = key
+                .strip_prefix("day")
+                .and_then(|d| d.parse::<u8>().ok())
+                .ok_or_else(|| {
+                    anyhow!(
+                        "Invalid key [{}] in {}, expected [dayDD]",
+                        key,
+                        path.display()
+                    )
+                })?;
+            let parts = value.as_table().ok_or_else(|| {
+                anyhow!("[{}] in {} must be a table", key, path.display())
+            })?;
+
+            for part in 1..=2 {
+                // Accept both `part1 = "123"` and `part1 = 123`
+                let answer = match parts.get(&format!("part{}", part)) {
+                    Some(toml::Value::String(s)) => Some(s.clone()),
+                    Some(toml::Value::Integer(n)) => Some(n.to_string()),
+                    Some(other) => anyhow::bail!(
+                        "[{}] part{} in {} must be a string or an integer, got {} {}",
+                        key,
+                        part,
+                        path.display(),
+                        other.type_str(),
+                        other
+                    ),
+                    None => None,
+                };
+                if let Some(answer) = answer {
+                    store.set(day, part, answer);
+                }
+            }
+        }
+
+        Ok(store)
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        let index = usize::from(part.checked_sub(1)?);
+        self.days.get(&day)?.get(index)?.as_deref()
+    }
+
+    pub fn set(&mut self, day: u8, part: u8, answer: impl Into<String>) {
+        if let Some(index) = part.checked_sub(1).filter(|i| *i < 2) {
+            self.days.entry(day).or_default()[usize::from(index)] =
+                Some(answer.into());
+        }
+    }
+
+    /// Write the store back to the file it was loaded from
+    pub fn save(&self) -> Result<()> {
+        let mut table = toml::Table::new();
+        for (day, parts) in &self.days {
+            let mut entry = toml::Table::new();
+            for (i, answer) in parts.iter().enumerate() {
+                if let Some(answer) = answer {
+                    entry.insert(
+                        format!("part{}", i + 1),
+                        toml::Value::String(answer.clone()),
+                    );
+                }
+            }
+            table.insert(format!("day{:02}", day), toml::Value::Table(entry));
+        }
+
+        write_atomic(&self.path, table.to_string())
+    }
+}
+
+/// Result of comparing a fresh answer against the store
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckStatus {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+/// Compare an answer against the accepted one, ignoring surrounding whitespace
+pub fn check_answer(expected: Option<&str>, actual: &str) -> CheckStatus {
+    match expected {
+        None => CheckStatus::Missing,
+        Some(expected) if expected.trim() == actual.trim() => CheckStatus::Pass,
+        Some(expected) => CheckStatus::Fail {
+            expected: expected.to_string(),
+        },
+    }
+}
+
+/// Assert that a day still produces its recorded answers.
+/// Used by the generated `cargo test` regression suite; days without an
+/// input file or recorded answers are skipped with a message, not failed.
+pub fn assert_recorded_answers(year: u16, day: u8) {
+    let label = format!("year {} day {:02}", year, day);
+
+    let store = AnswerStore::load(year)
+        .unwrap_or_else(|err| panic!("{}: {:#}", label, err));
+    let solver = SolutionRegistry::get_solver(year, day)
+        .unwrap_or_else(|| panic!("{}: not registered", label));
+    let Ok(input) = load_input(year, day) else {
+        println!("skipped {}: no input file", label);
+        return;
+    };
+
+    for part in 1..=2 {
+        let Some(expected) = store.get(day, part) else {
+            println!("skipped {} part {}: no recorded answer", label, part);
+            continue;
+        };
+        let part_solver = solver.part(part).expect("part 1 or 2");
+        let actual = part_solver(&input)
+            .unwrap_or_else(|err| panic!("{} part {}: {:#}", label, part, err));
+
+        if let CheckStatus::Fail { expected } =
+            check_answer(Some(expected), &actual)
+        {
+            panic!(
+                "{} part {}: answer changed\n  expected: {}\n    actual: {}",
+                label, part, expected, actual
+            );
+        }
+    }
+}
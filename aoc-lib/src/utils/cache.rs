@@ -0,0 +1,147 @@
+// `aoc-lib/src/utils/cache.rs`
+
+use anyhow::{bail, Context, Result};
+use std::ffi::OsString;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+/// A lock file older than this is left over from a crashed run and is removed
+pub const STALE_LOCK_AFTER: Duration = Duration::from_secs(120);
+
+const LOCK_POLL: Duration = Duration::from_millis(100);
+
+/// Reject cached content that cannot be a puzzle input: empty files
+/// (a crash mid-write) and HTML pages (an expired session's login page)
+pub fn validate_input(content: &str) -> Result<()> {
+    let trimmed = content.trim_start();
+    if trimmed.is_empty() {
+        bail!("it is empty");
+    }
+    let head = trimmed.get(..15).unwrap_or(trimmed).to_ascii_lowercase();
+    if head.starts_with("<!doctype") || head.starts_with("<html") {
+        bail!("it is an HTML page, not puzzle input");
+    }
+    Ok(())
+}
+
+// `dir/name.ext` -> `dir/name.ext<suffix>`
+pub(crate) fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().map(OsString::from).unwrap_or_default();
+    name.push(suffix);
+    path.with_file_name(name)
+}
+
+/// Write a file so readers see either the old content or the new, never a
+/// partial write: write a temp file next to it, sync, then rename over it
+pub fn write_atomic(path: &Path, content: impl AsRef<[u8]>) -> Result<()> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent).with_context(|| {
+            format!("Failed to create directory {}", parent.display())
+        })?;
+    }
+
+    let tmp = with_suffix(path, &format!(".tmp{}", std::process::id()));
+    let result = (|| {
+        let mut file = std::fs::File::create(&tmp)?;
+        file.write_all(content.as_ref())?;
+        file.sync_all()?;
+        std::fs::rename(&tmp, path)
+    })();
+
+    if result.is_err() {
+        let _ = std::fs::remove_file(&tmp);
+    }
+    result.with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Exclusive lock on a file, held as `<file>.lock` until dropped.
+/// Concurrent runs wait for each other; a lock older than
+/// `STALE_LOCK_AFTER` is assumed abandoned and taken over.
+#[derive(Debug)]
+pub struct FileLock {
+    path: PathBuf,
+}
+
+impl FileLock {
+    /// Lock `path`, waiting up to `STALE_LOCK_AFTER` for another holder
+    pub fn acquire(path: &Path) -> Result<Self> {
+        Self::acquire_with(path, STALE_LOCK_AFTER)
+    }
+
+    /// Lock `path`, treating locks older than `stale_after` as abandoned
+    pub fn acquire_with(path: &Path, stale_after: Duration) -> Result<Self> {
+        let lock_path = with_suffix(path, ".lock");
+        if let Some(parent) =
+            lock_path.parent().filter(|p| !p.as_os_str().is_empty())
+        {
+            std::fs::create_dir_all(parent).with_context(|| {
+                format!("Failed to create directory {}", parent.display())
+            })?;
+        }
+
+        let start = Instant::now();
+        loop {
+            match std::fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&lock_path)
+            {
+                Ok(mut file) => {
+                    // For humans inspecting a stuck lock
+                    let _ = writeln!(file, "pid {}", std::process::id());
+                    return Ok(Self { path: lock_path });
+                }
+                Err(err) if err.kind() == ErrorKind::AlreadyExists => {
+                    if let Some(stale) = lock_stamp(&lock_path)
+                        .filter(|(modified, _)| age(*modified) >= stale_after)
+                    {
+                        // Another waiter may have taken it over already and
+                        // created a fresh lock: remove the file only while it
+                        // is still the one found stale, then retry either way
+                        if lock_stamp(&lock_path).as_ref() == Some(&stale) {
+                            let _ = std::fs::remove_file(&lock_path);
+                        }
+                        continue;
+                    }
+                    if start.elapsed() >= stale_after {
+                        bail!(
+                            "Timed out waiting for {}; if no other aoc command is running, delete it",
+                            lock_path.display()
+                        );
+                    }
+                    std::thread::sleep(LOCK_POLL);
+                }
+                Err(err) => {
+                    return Err(err).with_context(|| {
+                        format!("Failed to create {}", lock_path.display())
+                    })
+                }
+            }
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+// A lock file's modification time and contents, which tell one lock file
+// from the next one created at the same path
+fn lock_stamp(path: &Path) -> Option<(SystemTime, String)> {
+    let modified = std::fs::metadata(path).ok()?.modified().ok()?;
+    let contents = std::fs::read_to_string(path).ok()?;
+    Some((modified, contents))
+}
+
+fn age(modified: SystemTime) -> Duration {
+    SystemTime::now()
+        .duration_since(modified)
+        .unwrap_or(Duration::ZERO)
+}
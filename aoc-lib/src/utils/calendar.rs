@@ -0,0 +1,152 @@
+// `aoc-lib/src/utils/calendar.rs`
+
+use crate::utils::output::format_remaining;
+use crate::SolutionRegistry;
+use anyhow::Result;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// Puzzles unlock at midnight EST (UTC-5), i.e. 05:00 UTC
+const UNLOCK_HOUR_UTC: u64 = 5;
+
+// Seconds since the Unix epoch, now
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Convert days since 1970-01-01 to a (year, month, day) civil date
+pub fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    // Howard Hinnant's days-to-civil algorithm
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Convert a (year, month, day) civil date to days since 1970-01-01
+pub fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    // Howard Hinnant's civil-to-days algorithm, the inverse of `civil_from_days`
+    let year = year - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let month = i64::from(month);
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let doy = (153 * mp + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// First year of Advent of Code
+pub const FIRST_YEAR: u16 = 2015;
+
+/// Last year accepted anywhere a year is given
+pub const LAST_YEAR: u16 = 2099;
+
+/// A year as typed on the command line: two-digit years are this
+/// century's, so 24 is 2024
+pub fn full_year(year: u16) -> u16 {
+    if year < 100 {
+        2000 + year
+    } else {
+        year
+    }
+}
+
+/// Fail unless `year` is an Advent of Code year
+pub fn ensure_valid_year(year: u16) -> Result<()> {
+    if !(FIRST_YEAR..=LAST_YEAR).contains(&year) {
+        anyhow::bail!(
+            "Year must be between {} and {} (Advent of Code years)",
+            FIRST_YEAR,
+            LAST_YEAR
+        );
+    }
+    Ok(())
+}
+
+/// Puzzles in an event by the published schedule: 25 until 2024,
+/// 12 from 2025 on. Years with solutions declare theirs as `DAY_COUNT`.
+pub fn default_day_count(year: u16) -> u8 {
+    if year >= 2025 {
+        12
+    } else {
+        25
+    }
+}
+
+/// Puzzles in a year's event: the year module's `DAY_COUNT` when it is
+/// registered, else `default_day_count`
+pub fn day_count(year: u16) -> u8 {
+    SolutionRegistry::day_count(year)
+}
+
+/// Fail unless `day` is one of the year's puzzle days
+pub fn ensure_valid_day(year: u16, day: u8) -> Result<()> {
+    let count = day_count(year);
+    if !(1..=count).contains(&day) {
+        anyhow::bail!(
+            "Day must be between 1 and {} ({} has {} puzzles)",
+            count,
+            year,
+            count
+        );
+    }
+    Ok(())
+}
+
+/// The last day of an event has only part 1; its second star is
+/// awarded on the site once every other star is collected
+pub fn is_last_day(year: u16, day: u8) -> bool {
+    day == day_count(year)
+}
+
+/// Unix seconds at which a puzzle unlocks: December `day`, midnight EST
+pub fn unlock_time(year: u16, day: u8) -> u64 {
+    let days = days_from_civil(i64::from(year), 12, u32::from(day));
+    days as u64 * 86_400 + UNLOCK_HOUR_UTC * 3600
+}
+
+/// Time left until a puzzle unlocks, or `None` once it is available
+pub fn time_until_unlock(year: u16, day: u8) -> Option<Duration> {
+    let unlock = unlock_time(year, day);
+    let now = unix_now();
+    (now < unlock).then(|| Duration::from_secs(unlock - now))
+}
+
+/// Fail with the unlock time and time remaining if a puzzle is still locked
+pub fn ensure_unlocked(year: u16, day: u8) -> Result<()> {
+    if let Some(remaining) = time_until_unlock(year, day) {
+        anyhow::bail!(
+            "{} day {} is not unlocked yet: it unlocks at {} (midnight EST), in {}",
+            year,
+            day,
+            format_utc(unlock_time(year, day)),
+            format_remaining(remaining)
+        );
+    }
+    Ok(())
+}
+
+/// Format Unix seconds as "2024-12-05 05:00:00 UTC"
+pub fn format_utc(unix_secs: u64) -> String {
+    let secs = unix_secs as i64;
+    let (year, month, day) = civil_from_days(secs.div_euclid(86_400));
+    let rem = secs.rem_euclid(86_400);
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        rem / 3600,
+        rem % 3600 / 60,
+        rem % 60
+    )
+}
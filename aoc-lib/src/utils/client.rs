@@ -0,0 +1,523 @@
+// `aoc-lib/src/utils/client.rs`
+
+use crate::utils::cache::validate_input;
+use crate::utils::calendar::{ensure_unlocked, ensure_valid_day};
+use crate::utils::config::Config;
+use crate::utils::session::{normalize_session, resolve_session};
+use crate::utils::throttle::{get_request_log_path, RequestLog, RetryPolicy};
+use anyhow::{anyhow, Context, Result};
+use colored::*;
+use std::time::Duration;
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Base URL of the Advent of Code site.
+/// Override with AOC_BASE_URL, e.g. to point at a local stand-in server.
+pub fn aoc_base_url() -> String {
+    std::env::var("AOC_BASE_URL")
+        .map(|url| url.trim_end_matches('/').to_string())
+        .unwrap_or_else(|_| DEFAULT_BASE_URL.to_string())
+}
+
+/// User-Agent sent with every request. AoC asks automated tools to
+/// identify themselves, so it carries the user's contact when configured.
+pub fn user_agent(contact: Option<&str>) -> String {
+    format!(
+        "aoc-lib/{} ({})",
+        env!("CARGO_PKG_VERSION"),
+        contact.unwrap_or("contact not configured")
+    )
+}
+
+/// Status and body of an HTTP response
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+impl HttpResponse {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+/// How `AocClient` talks HTTP. The default is `ReqwestTransport`;
+/// tests can inject a fake that never touches the network.
+pub trait Transport {
+    /// GET `url`, sending `session` as the session cookie if given
+    fn get(&self, url: &str, session: Option<&str>) -> Result<HttpResponse>;
+
+    /// POST a url-encoded form to `url`
+    fn post_form(
+        &self,
+        url: &str,
+        session: Option<&str>,
+        form: &[(&str, &str)],
+    ) -> Result<HttpResponse>;
+}
+
+/// Blocking reqwest transport used against the real site
+pub struct ReqwestTransport {
+    client: reqwest::blocking::Client,
+}
+
+impl ReqwestTransport {
+    /// Transport with the default User-Agent and no contact
+    pub fn new() -> Result<Self> {
+        Self::with_user_agent(&user_agent(None))
+    }
+
+    pub fn with_user_agent(user_agent: &str) -> Result<Self> {
+        let client = reqwest::blocking::Client::builder()
+            .user_agent(user_agent)
+            .build()
+            .context("Failed to build HTTP client")?;
+        Ok(Self { client })
+    }
+
+    fn send(
+        request: reqwest::blocking::RequestBuilder,
+        session: Option<&str>,
+    ) -> Result<HttpResponse> {
+        let request = match session {
+            Some(session) => {
+                request.header("Cookie", format!("session={}", session))
+            }
+            None => request,
+        };
+        let response =
+            request.send().context("Failed to send request to AoC")?;
+        let status = response.status().as_u16();
+        let body = response.text().context("Failed to read response text")?;
+        Ok(HttpResponse { status, body })
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn get(&self, url: &str, session: Option<&str>) -> Result<HttpResponse> {
+        Self::send(self.client.get(url), session)
+    }
+
+    fn post_form(
+        &self,
+        url: &str,
+        session: Option<&str>,
+        form: &[(&str, &str)],
+    ) -> Result<HttpResponse> {
+        Self::send(self.client.post(url).form(form), session)
+    }
+}
+
+/// Client for every Advent of Code request: input downloads, puzzle pages
+/// and answer submissions all go through one base URL and transport
+pub struct AocClient {
+    base_url: String,
+    session: Option<String>,
+    transport: Box<dyn Transport>,
+    log: Option<RequestLog>,
+    retry: RetryPolicy,
+}
+
+impl AocClient {
+    /// Client with an explicit base URL, session token and transport.
+    /// Retries 5xx responses with the default policy but keeps no request
+    /// log; add one with `with_request_log`.
+    pub fn new(
+        base_url: impl Into<String>,
+        session: Option<String>,
+        transport: Box<dyn Transport>,
+    ) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.map(|s| normalize_session(&s)),
+            transport,
+            log: None,
+            retry: RetryPolicy::default(),
+        }
+    }
+
+    /// Client configured from AOC_BASE_URL, the resolved session token
+    /// (`--session`, AOC_SESSION, then the profile's session file) and the
+    /// user config: contact for the User-Agent, request interval and retries
+    pub fn from_env() -> Result<Self> {
+        let config = Config::load()?;
+        let contact = config.contact();
+        if contact.is_none() {
+            eprintln!(
+                "{}",
+                "Warning: no contact configured for the User-Agent. Set AOC_CONTACT or \
+                 `contact` in ~/.config/aoc/config.toml (e.g. your repo URL or email)."
+                    .yellow()
+            );
+        }
+
+        let session = resolve_session()?.map(|session| session.token);
+        let transport =
+            ReqwestTransport::with_user_agent(&user_agent(contact.as_deref()))?;
+        let mut retry = RetryPolicy::default();
+        if let Some(max_retries) = config.max_retries {
+            retry.max_retries = max_retries;
+        }
+
+        Ok(Self::new(aoc_base_url(), session, Box::new(transport))
+            .with_request_log(RequestLog::new(
+                get_request_log_path()?,
+                config.min_interval()?,
+            ))
+            .with_retry(retry))
+    }
+
+    /// Pace requests through a persistent log
+    pub fn with_request_log(mut self, log: RequestLog) -> Self {
+        self.log = Some(log);
+        self
+    }
+
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn session(&self) -> Result<&str> {
+        self.session.as_deref().ok_or_else(|| {
+            anyhow!(
+                "No AoC session token found. Pass --session, set AOC_SESSION, \
+                     or save one with: cargo run --bin aoc auth set"
+            )
+        })
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    // Every request goes through here: wait for the minimum interval,
+    // log the request, and retry 5xx responses to GETs with backoff. A POST
+    // gets one attempt: a 5xx does not prove an answer was not recorded,
+    // and submitting it twice can earn a wrong-answer lockout.
+    fn send(
+        &self,
+        method: &str,
+        url: &str,
+        request: impl Fn() -> Result<HttpResponse>,
+    ) -> Result<HttpResponse> {
+        let mut retry = 0;
+        loop {
+            if let Some(log) = &self.log {
+                log.wait_turn();
+            }
+            let result = request();
+            if let Some(log) = &self.log {
+                log.record(
+                    method,
+                    url,
+                    result.as_ref().ok().map(|r| r.status),
+                )?;
+            }
+
+            match result {
+                Ok(response)
+                    if response.status >= 500
+                        && method == "GET"
+                        && retry < self.retry.max_retries =>
+                {
+                    let delay = self.retry.delay(retry);
+                    retry += 1;
+                    eprintln!(
+                        "{}",
+                        format!(
+                            "AoC answered HTTP {}; retry {}/{} in {:.1}s",
+                            response.status,
+                            retry,
+                            self.retry.max_retries,
+                            delay.as_secs_f64()
+                        )
+                        .yellow()
+                    );
+                    std::thread::sleep(delay);
+                }
+                result => return result,
+            }
+        }
+    }
+
+    fn get(&self, url: &str, session: Option<&str>) -> Result<HttpResponse> {
+        self.send("GET", url, || self.transport.get(url, session))
+    }
+
+    fn post_form(
+        &self,
+        url: &str,
+        session: Option<&str>,
+        form: &[(&str, &str)],
+    ) -> Result<HttpResponse> {
+        self.send("POST", url, || self.transport.post_form(url, session, form))
+    }
+
+    /// Download a day's puzzle input
+    pub fn download_input(&self, year: u16, day: u8) -> Result<String> {
+        // basic day guard
+        ensure_valid_day(year, day)?;
+        ensure_unlocked(year, day)?;
+
+        let url = self.url(&format!("/{}/day/{}/input", year, day));
+        let response = self.get(&url, Some(self.session()?))?;
+
+        if !response.is_success() {
+            anyhow::bail!(
+                "Failed to download input: HTTP {}{}",
+                response.status,
+                not_unlocked_note(&response)
+            );
+        }
+
+        let text = response.body;
+
+        // detect empty or HTML login page, by the same rules as the cache
+        if let Err(reason) = validate_input(&text) {
+            anyhow::bail!(
+                "Downloaded empty or HTML content ({}). Verify the session token (aoc auth check) and puzzle availability.",
+                reason
+            );
+        }
+
+        Ok(text)
+    }
+
+    /// Fetch a day's puzzle page as HTML. Sends the session when set,
+    /// so part 2 is included once it is unlocked.
+    pub fn fetch_puzzle(&self, year: u16, day: u8) -> Result<String> {
+        ensure_valid_day(year, day)?;
+        ensure_unlocked(year, day)?;
+        let url = self.url(&format!("/{}/day/{}", year, day));
+        let response = self.get(&url, self.session.as_deref())?;
+
+        if !response.is_success() {
+            anyhow::bail!(
+                "Failed to fetch puzzle page: HTTP {}{}",
+                response.status,
+                not_unlocked_note(&response)
+            );
+        }
+
+        Ok(response.body)
+    }
+
+    /// Check the session against the site: the logged-in user,
+    /// or `None` if the site treats the token as logged out
+    pub fn fetch_user(&self) -> Result<Option<AocUser>> {
+        let url = self.url("/");
+        let response = self.get(&url, Some(self.session()?))?;
+
+        if !response.is_success() {
+            anyhow::bail!(
+                "Failed to fetch AoC home page: HTTP {}",
+                response.status
+            );
+        }
+
+        Ok(parse_user(&response.body))
+    }
+
+    /// Submit an answer for one part of a puzzle
+    pub fn submit_answer(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<SubmitOutcome> {
+        if !(1..=2).contains(&part) {
+            anyhow::bail!("Part must be 1 or 2");
+        }
+        ensure_valid_day(year, day)?;
+        ensure_unlocked(year, day)?;
+
+        let url = self.url(&format!("/{}/day/{}/answer", year, day));
+        let level = part.to_string();
+        let form = [("level", level.as_str()), ("answer", answer.trim())];
+        let response = self
+            .post_form(&url, Some(self.session()?), &form)
+            .context("Failed to send answer to AoC")?;
+
+        if !response.is_success() {
+            anyhow::bail!(
+                "Failed to submit answer: HTTP {}{}",
+                response.status,
+                not_unlocked_note(&response)
+            );
+        }
+
+        Ok(parse_submit_response(&response.body))
+    }
+}
+
+// The site answers 404 with this text while a puzzle is locked
+fn not_unlocked_note(response: &HttpResponse) -> &'static str {
+    if response.status == 404 && response.body.contains("before it unlocks") {
+        " (the puzzle is not unlocked yet)"
+    } else {
+        ""
+    }
+}
+
+/// The logged-in user shown in the site header
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AocUser {
+    pub name: String,
+    /// Stars in the year shown on the page, if displayed
+    pub stars: Option<u32>,
+}
+
+/// Parse the header's `<div class="user">name <span class="star-count">N*</span></div>`
+pub fn parse_user(html: &str) -> Option<AocUser> {
+    let (_, rest) = html.split_once("<div class=\"user\">")?;
+    let (div, _) = rest.split_once("</div>")?;
+
+    let (name_part, stars) = match div.split_once("<span class=\"star-count\">")
+    {
+        Some((name, rest)) => {
+            let stars = rest.split_once("</span>").and_then(|(count, _)| {
+                count.trim().trim_end_matches('*').parse().ok()
+            });
+            (name, stars)
+        }
+        None => (div, None),
+    };
+    // Drop badges such as the AoC++ link that follow the name
+    let name = name_part.split('<').next().unwrap_or_default().trim();
+    if name.is_empty() {
+        return None;
+    }
+
+    Some(AocUser {
+        name: name.to_string(),
+        stars,
+    })
+}
+
+/// Hint AoC gives alongside a wrong answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnswerHint {
+    TooHigh,
+    TooLow,
+}
+
+/// Outcome of submitting an answer, parsed from the AoC response page
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    Correct,
+    /// Wrong answer, with the too high/too low hint and lockout if given
+    Wrong {
+        hint: Option<AnswerHint>,
+        wait: Option<Duration>,
+    },
+    /// Answered too recently; `wait` is the time left before retrying
+    RateLimited {
+        wait: Option<Duration>,
+    },
+    /// The part was already solved (or is not unlocked yet)
+    AlreadySolved,
+    /// Response did not match any known message; holds the page text
+    Unknown(String),
+}
+
+/// Parse the HTML page AoC returns after an answer submission
+pub fn parse_submit_response(html: &str) -> SubmitOutcome {
+    // The message lives in the page's <article>; fall back to the whole page
+    let article = html
+        .split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map(|(article, _)| article)
+        .unwrap_or(html);
+    let text = strip_tags(article, " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    let lower = text.to_lowercase();
+
+    if lower.contains("that's the right answer") {
+        SubmitOutcome::Correct
+    } else if lower.contains("that's not the right answer") {
+        let hint = if lower.contains("too high") {
+            Some(AnswerHint::TooHigh)
+        } else if lower.contains("too low") {
+            Some(AnswerHint::TooLow)
+        } else {
+            None
+        };
+        SubmitOutcome::Wrong {
+            hint,
+            wait: parse_lockout(&lower),
+        }
+    } else if lower.contains("you gave an answer too recently") {
+        SubmitOutcome::RateLimited {
+            wait: parse_time_left(&lower),
+        }
+    } else if lower.contains("did you already complete it") {
+        SubmitOutcome::AlreadySolved
+    } else {
+        SubmitOutcome::Unknown(text.trim().to_string())
+    }
+}
+
+// Remove HTML tags, putting `gap` where each one was: " " keeps the words
+// of neighbouring elements apart, "" keeps text such as the `<em>`
+// highlights inside a code block exactly as written
+pub(crate) fn strip_tags(html: &str, gap: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => {
+                in_tag = false;
+                text.push_str(gap);
+            }
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+// "You have 1m 25s left to wait." -> 85s
+fn parse_time_left(text: &str) -> Option<Duration> {
+    let (before, _) = text.split_once("left to wait")?;
+    let (_, amount) = before.rsplit_once("you have")?;
+    let mut secs = 0;
+    for token in amount.split_whitespace() {
+        let (number, unit) =
+            token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+        let number: u64 = number.parse().ok()?;
+        secs += match unit {
+            "h" => number * 3600,
+            "m" => number * 60,
+            "s" => number,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+// "please wait one minute before trying again" / "please wait 5 minutes ..."
+fn parse_lockout(text: &str) -> Option<Duration> {
+    let (_, rest) = text.split_once("please wait ")?;
+    let (amount, _) = rest.split_once(" before trying again")?;
+    let mut words = amount.split_whitespace();
+    let number = match words.next()? {
+        "one" | "a" => 1,
+        n => n.parse().ok()?,
+    };
+    match words.next()? {
+        "second" | "seconds" => Some(Duration::from_secs(number)),
+        "minute" | "minutes" => Some(Duration::from_secs(number * 60)),
+        "hour" | "hours" => Some(Duration::from_secs(number * 3600)),
+        _ => None,
+    }
+}
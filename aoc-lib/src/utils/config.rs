@@ -0,0 +1,82 @@
+// `aoc-lib/src/utils/config.rs`
+
+use crate::utils::session::config_dir;
+use anyhow::{anyhow, Context, Result};
+use serde::Deserialize;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Default gap between two requests to the site
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(3);
+
+// Get the path to the user config file: `~/.config/aoc/config.toml`
+pub fn get_config_path() -> Result<PathBuf> {
+    Ok(config_dir()?.join("config.toml"))
+}
+
+/// User settings from `~/.config/aoc/config.toml`; every key is optional.
+///
+/// ```toml
+/// contact = "https://github.com/you/aoc (you@example.com)"
+/// min_interval = 5   # seconds between requests
+/// max_retries = 3    # retries on HTTP 5xx
+/// input_dir = "../aoc-inputs"                  # relative to the workspace root
+/// input_layout = "{year}/day{day}/input.txt"   # under input_dir
+/// input_key_file = "/home/you/.aoc-input.key"  # encrypt inputs at rest
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub contact: Option<String>,
+    pub min_interval: Option<f64>,
+    pub max_retries: Option<u32>,
+    pub input_dir: Option<PathBuf>,
+    pub input_layout: Option<String>,
+    pub input_key_file: Option<PathBuf>,
+}
+
+impl Config {
+    /// Load the config file; a missing file is the default config
+    pub fn load() -> Result<Self> {
+        let path = get_config_path()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        toml::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// Contact for the User-Agent: AOC_CONTACT, then `contact`
+    pub fn contact(&self) -> Option<String> {
+        std::env::var("AOC_CONTACT")
+            .ok()
+            .or_else(|| self.contact.clone())
+            .map(|c| c.trim().to_string())
+            .filter(|c| !c.is_empty())
+    }
+
+    /// Minimum gap between requests: AOC_MIN_INTERVAL (seconds),
+    /// then `min_interval`, then `DEFAULT_MIN_INTERVAL`
+    pub fn min_interval(&self) -> Result<Duration> {
+        let secs = match std::env::var("AOC_MIN_INTERVAL") {
+            Ok(value) => Some(value.trim().parse::<f64>().with_context(|| {
+                format!("AOC_MIN_INTERVAL must be a number of seconds, got \"{}\"", value)
+            })?),
+            Err(_) => self.min_interval,
+        };
+        match secs {
+            // Rejects negative, NaN and too large values rather than panicking
+            Some(secs) => Duration::try_from_secs_f64(secs).map_err(|err| {
+                anyhow!(
+                    "Invalid minimum request interval {} seconds: {}",
+                    secs,
+                    err
+                )
+            }),
+            None => Ok(DEFAULT_MIN_INTERVAL),
+        }
+    }
+}
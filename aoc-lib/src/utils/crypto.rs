@@ -0,0 +1,226 @@
+// `aoc-lib/src/utils/crypto.rs`
+
+use crate::utils::cache::{with_suffix, write_atomic};
+use crate::utils::paths::{from_root, layout_matches, settings};
+use crate::utils::session::{config_dir, validate_profile};
+use anyhow::{anyhow, bail, Context, Result};
+use argon2::Argon2;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+// File format: MAGIC, 16-byte salt, 12-byte nonce, ChaCha20-Poly1305
+// ciphertext. The file key is Argon2id(secret, salt): every guess at the
+// secret costs a memory-hard hash, and no guess carries over between salts.
+const MAGIC: &[u8] = b"AOCENC2\n";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+type Salt = [u8; SALT_LEN];
+
+/// Encrypted copy of an input file: `dayDD.txt` -> `dayDD.txt.enc`
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    with_suffix(path, ".enc")
+}
+
+// Default key file: `~/.config/aoc/input.key`
+pub fn get_key_path() -> Result<PathBuf> {
+    Ok(config_dir()?.join("input.key"))
+}
+
+/// Secret for encrypted inputs. Each file's key is derived from it with
+/// Argon2id and the random salt stored in the file's header.
+pub struct InputKey {
+    secret: String,
+    // Salt for the files this key writes, so a batch derives one key
+    salt: Salt,
+    // File keys derived so far, by salt
+    derived: Mutex<HashMap<Salt, [u8; 32]>>,
+}
+
+impl InputKey {
+    /// Use any secret string; surrounding whitespace is ignored
+    pub fn from_secret(secret: &str) -> Result<Self> {
+        let secret = secret.trim();
+        if secret.is_empty() {
+            bail!("Input encryption key is empty");
+        }
+        let mut salt = Salt::default();
+        OsRng.fill_bytes(&mut salt);
+        Ok(Self {
+            secret: secret.to_string(),
+            salt,
+            derived: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// The configured key: AOC_INPUT_KEY, then the file named by
+    /// `input_key_file` in the config, then `~/.config/aoc/input.key`.
+    /// `None` means inputs are stored as plain text.
+    pub fn load() -> Result<Option<Self>> {
+        if let Ok(secret) = std::env::var("AOC_INPUT_KEY") {
+            return Self::from_secret(&secret).map(Some);
+        }
+
+        let (path, required) = match &settings().input_key_file {
+            Some(path) => (from_root(path.clone()), true),
+            None => (get_key_path()?, false),
+        };
+        if !path.exists() {
+            if required {
+                bail!("Input key file not found: {}", path.display());
+            }
+            return Ok(None);
+        }
+
+        let secret = std::fs::read_to_string(&path).with_context(|| {
+            format!("Failed to read input key file {}", path.display())
+        })?;
+        Self::from_secret(&secret)
+            .with_context(|| {
+                format!("Invalid input key file {}", path.display())
+            })
+            .map(Some)
+    }
+
+    // Cipher for the file key under `salt`, derived once per salt
+    fn cipher(&self, salt: &Salt) -> Result<ChaCha20Poly1305> {
+        let mut derived =
+            self.derived.lock().unwrap_or_else(|e| e.into_inner());
+        let key = match derived.get(salt) {
+            Some(key) => *key,
+            None => {
+                let mut key = [0u8; 32];
+                Argon2::default()
+                    .hash_password_into(self.secret.as_bytes(), salt, &mut key)
+                    .map_err(|err| {
+                        anyhow!("Failed to derive input key: {}", err)
+                    })?;
+                *derived.entry(*salt).or_insert(key)
+            }
+        };
+        Ok(ChaCha20Poly1305::new(Key::from_slice(&key)))
+    }
+
+    /// Encrypt with this key's salt and a fresh random nonce
+    pub fn encrypt(&self, plaintext: &[u8]) -> Result<Vec<u8>> {
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .cipher(&self.salt)?
+            .encrypt(&nonce, plaintext)
+            .map_err(|_| anyhow!("Failed to encrypt input"))?;
+
+        let mut data = Vec::with_capacity(
+            MAGIC.len() + SALT_LEN + NONCE_LEN + ciphertext.len(),
+        );
+        data.extend_from_slice(MAGIC);
+        data.extend_from_slice(&self.salt);
+        data.extend_from_slice(&nonce);
+        data.extend_from_slice(&ciphertext);
+        Ok(data)
+    }
+
+    /// Decrypt data written by `encrypt`; fails on a wrong key or tampering
+    pub fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>> {
+        let body = data
+            .strip_prefix(MAGIC)
+            .ok_or_else(|| anyhow!("Not an encrypted input file"))?;
+        if body.len() < SALT_LEN + NONCE_LEN {
+            bail!("Encrypted input file is truncated");
+        }
+        let (salt, body) = body.split_at(SALT_LEN);
+        let (nonce, ciphertext) = body.split_at(NONCE_LEN);
+        let salt: Salt = salt.try_into().expect("salt is SALT_LEN bytes");
+
+        self.cipher(&salt)?
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| {
+                anyhow!("Failed to decrypt input: wrong key or corrupted file")
+            })
+    }
+}
+
+/// Input files under `root`, recursively and sorted: files named by the
+/// input `layout` (under a profile directory too, when the layout has no
+/// `{profile}`) or, with `encrypted`, their `.enc` copies. Anything else
+/// under the root, examples included, is left alone.
+pub fn find_inputs(
+    root: &Path,
+    layout: &str,
+    encrypted: bool,
+) -> Result<Vec<PathBuf>> {
+    let mut found = Vec::new();
+    let mut dirs = vec![root.to_path_buf()];
+
+    while let Some(dir) = dirs.pop() {
+        let entries = std::fs::read_dir(&dir).with_context(|| {
+            format!("Failed to read directory {}", dir.display())
+        })?;
+        for entry in entries {
+            let path = entry?.path();
+            if path.is_dir() {
+                dirs.push(path);
+                continue;
+            }
+            if is_input_file(root, layout, &path, encrypted) {
+                found.push(path);
+            }
+        }
+    }
+
+    found.sort();
+    Ok(found)
+}
+
+// Whether a file under `root` is an input (or its `.enc` copy) by the layout
+fn is_input_file(
+    root: &Path,
+    layout: &str,
+    path: &Path,
+    encrypted: bool,
+) -> bool {
+    let Ok(relative) = path.strip_prefix(root) else {
+        return false;
+    };
+    let relative = relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+    let relative = match (encrypted, relative.strip_suffix(".enc")) {
+        (true, Some(plain)) => plain,
+        (false, None) => relative.as_str(),
+        _ => return false,
+    };
+
+    layout_matches(layout, relative)
+        || (!layout.contains("{profile}")
+            && relative.split_once('/').is_some_and(|(profile, rest)| {
+                validate_profile(profile).is_ok()
+                    && layout_matches(layout, rest)
+            }))
+}
+
+/// Write the encrypted copy of a plain input file; returns its path
+pub fn encrypt_file(key: &InputKey, path: &Path) -> Result<PathBuf> {
+    let plaintext = std::fs::read(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let target = encrypted_path(path);
+    write_atomic(&target, key.encrypt(&plaintext)?)?;
+    Ok(target)
+}
+
+/// Write the plain copy of an encrypted input file; returns its path
+pub fn decrypt_file(key: &InputKey, path: &Path) -> Result<PathBuf> {
+    let data = std::fs::read(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let plaintext = key
+        .decrypt(&data)
+        .with_context(|| format!("Failed to decrypt {}", path.display()))?;
+    let target = path.with_extension("");
+    write_atomic(&target, plaintext)?;
+    Ok(target)
+}
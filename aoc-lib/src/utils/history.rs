@@ -0,0 +1,233 @@
+// `aoc-lib/src/utils/history.rs`
+
+use crate::utils::cache::write_atomic;
+use crate::utils::calendar::unix_now;
+use crate::utils::paths::answers_root;
+use crate::utils::session::profile_dir;
+use crate::utils::{AnswerHint, SubmitOutcome};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+// Get the path to a year's submission history: `answers/yearYYYY.history.toml`
+// in the workspace root (`answers/<profile>/...` for named profiles)
+pub fn get_history_path(year: u16) -> Result<PathBuf> {
+    Ok(
+        profile_dir(&answers_root())?
+            .join(format!("year{}.history.toml", year)),
+    )
+}
+
+/// What AoC said about a submitted answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AttemptOutcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    RateLimited,
+    AlreadySolved,
+    Unknown,
+}
+
+impl AttemptOutcome {
+    /// Whether AoC rejected the answer itself (not just the timing)
+    pub fn is_rejected(self) -> bool {
+        matches!(
+            self,
+            AttemptOutcome::Wrong
+                | AttemptOutcome::TooHigh
+                | AttemptOutcome::TooLow
+        )
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            AttemptOutcome::Correct => "correct",
+            AttemptOutcome::Wrong => "wrong",
+            AttemptOutcome::TooHigh => "too high",
+            AttemptOutcome::TooLow => "too low",
+            AttemptOutcome::RateLimited => "rate limited",
+            AttemptOutcome::AlreadySolved => "already solved",
+            AttemptOutcome::Unknown => "unknown",
+        }
+    }
+}
+
+impl From<&SubmitOutcome> for AttemptOutcome {
+    fn from(outcome: &SubmitOutcome) -> Self {
+        match outcome {
+            SubmitOutcome::Correct => AttemptOutcome::Correct,
+            SubmitOutcome::Wrong {
+                hint: Some(AnswerHint::TooHigh),
+                ..
+            } => AttemptOutcome::TooHigh,
+            SubmitOutcome::Wrong {
+                hint: Some(AnswerHint::TooLow),
+                ..
+            } => AttemptOutcome::TooLow,
+            SubmitOutcome::Wrong { hint: None, .. } => AttemptOutcome::Wrong,
+            SubmitOutcome::RateLimited { .. } => AttemptOutcome::RateLimited,
+            SubmitOutcome::AlreadySolved => AttemptOutcome::AlreadySolved,
+            SubmitOutcome::Unknown(_) => AttemptOutcome::Unknown,
+        }
+    }
+}
+
+/// One recorded submission
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: AttemptOutcome,
+    /// Unix seconds
+    pub submitted_at: u64,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct HistoryFile {
+    #[serde(default)]
+    attempt: Vec<Attempt>,
+}
+
+/// Every answer submitted for one year, stored as `answers/yearYYYY.history.toml`
+pub struct SubmissionHistory {
+    year: u16,
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl SubmissionHistory {
+    /// Load a year's history; a missing file is an empty history
+    pub fn load(year: u16) -> Result<Self> {
+        Self::load_from(year, &get_history_path(year)?)
+    }
+
+    /// Load a year's history from `path`, which `record` then writes back to
+    pub fn load_from(year: u16, path: &Path) -> Result<Self> {
+        let file = if path.exists() {
+            let content = std::fs::read_to_string(path).with_context(|| {
+                format!("Failed to read {}", path.display())
+            })?;
+            toml::from_str::<HistoryFile>(&content).with_context(|| {
+                format!("Failed to parse {}", path.display())
+            })?
+        } else {
+            HistoryFile::default()
+        };
+
+        Ok(Self {
+            year,
+            path: path.to_path_buf(),
+            attempts: file.attempt,
+        })
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Attempts for one day (and optionally one part), oldest first
+    pub fn attempts(&self, day: u8, part: Option<u8>) -> Vec<&Attempt> {
+        self.attempts
+            .iter()
+            .filter(|a| a.day == day && part.map_or(true, |p| a.part == p))
+            .collect()
+    }
+
+    /// Whether AoC accepted an answer for this part, or said it was solved
+    pub fn is_solved(&self, day: u8, part: u8) -> bool {
+        self.attempts(day, Some(part)).iter().any(|a| {
+            matches!(
+                a.outcome,
+                AttemptOutcome::Correct | AttemptOutcome::AlreadySolved
+            )
+        })
+    }
+
+    /// Record a submission and write the history file
+    pub fn record(
+        &mut self,
+        day: u8,
+        part: u8,
+        answer: &str,
+        outcome: AttemptOutcome,
+    ) -> Result<()> {
+        self.attempts.push(Attempt {
+            day,
+            part,
+            answer: answer.trim().to_string(),
+            outcome,
+            submitted_at: unix_now(),
+        });
+        self.save()
+    }
+
+    /// Reason to refuse submitting `answer`, based on earlier responses:
+    /// already accepted, already rejected, or outside a too high/too low bound
+    pub fn refusal(&self, day: u8, part: u8, answer: &str) -> Option<String> {
+        let answer = answer.trim();
+        let attempts = self.attempts(day, Some(part));
+
+        if let Some(correct) = attempts
+            .iter()
+            .find(|a| a.outcome == AttemptOutcome::Correct)
+        {
+            return Some(format!(
+                "part {} was already solved with {}",
+                part, correct.answer
+            ));
+        }
+
+        if let Some(rejected) = attempts
+            .iter()
+            .find(|a| a.answer == answer && a.outcome.is_rejected())
+        {
+            return Some(format!(
+                "{} was already rejected ({})",
+                answer,
+                rejected.outcome.label()
+            ));
+        }
+
+        // Numeric answers must fall strictly between the known bounds
+        let value = answer.parse::<i128>().ok()?;
+        let bound = |outcome| {
+            attempts
+                .iter()
+                .filter(move |a| a.outcome == outcome)
+                .filter_map(|a| a.answer.parse::<i128>().ok())
+        };
+
+        if let Some(high) = bound(AttemptOutcome::TooHigh).min() {
+            if value >= high {
+                return Some(format!(
+                    "{} was too high, so {} is too",
+                    high, value
+                ));
+            }
+        }
+        if let Some(low) = bound(AttemptOutcome::TooLow).max() {
+            if value <= low {
+                return Some(format!(
+                    "{} was too low, so {} is too",
+                    low, value
+                ));
+            }
+        }
+
+        None
+    }
+
+    fn save(&self) -> Result<()> {
+        let file = HistoryFile {
+            attempt: self.attempts.clone(),
+        };
+        let content = toml::to_string(&file)
+            .context("Failed to serialize submission history")?;
+
+        write_atomic(&self.path, content)
+    }
+}
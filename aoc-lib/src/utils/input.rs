@@ -1,19 +1,98 @@
 // `aoc-lib/src/utils/input.rs`
 
+use crate::utils::cache::{validate_input, write_atomic, FileLock};
+use crate::utils::client::{AocClient, SubmitOutcome};
+use crate::utils::crypto::{encrypted_path, InputKey};
+use crate::utils::paths::{input_layout, input_root, render_layout};
+use crate::utils::session::{active_profile, profile_dir};
 use anyhow::{anyhow, Context, Result};
-use std::path::PathBuf;
+use colored::*;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
-// Get the path to an input file for a specific year and day
-pub fn get_input_path(year: u16, day: u8) -> PathBuf {
-    PathBuf::from(format!("input/year{}/day{:02}.txt", year, day))
+/// Where a day's input is read from
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The cached `input/yearYYYY/dayDD.txt`
+    #[default]
+    Default,
+    /// An explicit file, e.g. a colleague's input or a hand-made edge case
+    File(PathBuf),
+    /// Standard input (`--input -`)
+    Stdin,
+    /// A numbered example, `input/yearYYYY/dayDD.exampleN.txt`
+    Example(u8),
 }
 
-// Load input file as a single string
-pub fn load_input(year: u16, day: u8) -> Result<String> {
-    let path = get_input_path(year, day);
+impl FromStr for InputSource {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        })
+    }
+}
+
+// Get the path to an input file for a specific year and day: the input
+// layout under the input root, `input/yearYYYY/dayDD.txt` by default.
+// Inputs differ per account, so named profiles get `input/<profile>/...`
+// unless the layout places `{profile}` itself.
+pub fn get_input_path(year: u16, day: u8) -> Result<PathBuf> {
+    let layout = input_layout();
+    let profile = active_profile()?;
+    let root = if layout.contains("{profile}") {
+        input_root()
+    } else {
+        profile_dir(&input_root())?
+    };
+    Ok(root.join(render_layout(layout, year, day, profile.as_deref())))
+}
+
+// Get the path to a numbered example input: `input/yearYYYY/dayDD.exampleN.txt`
+// under the input root. Examples are the same for every account and layout.
+pub fn get_example_path(year: u16, day: u8, example: u8) -> PathBuf {
+    input_root()
+        .join(format!("year{}/day{:02}.example{}.txt", year, day, example))
+}
+
+// Load a numbered example input as a single string
+pub fn load_example(year: u16, day: u8, example: u8) -> Result<String> {
+    let path = get_example_path(year, day, example);
 
     if !path.exists() {
         return Err(anyhow!(
+            "Example file not found - {}\n\n\
+            Paste the example from the puzzle description into that file.",
+            path.display()
+        ));
+    }
+    std::fs::read_to_string(&path).with_context(|| {
+        format!("Failed to read example file: {}", path.display())
+    })
+}
+
+// Load input file as a single string. Empty or HTML files (a crash
+// mid-write, an expired session's login page) are refused, not solved.
+pub fn load_input(year: u16, day: u8) -> Result<String> {
+    let path = get_input_path(year, day)?;
+
+    match read_stored(&path)? {
+        Some((from, content)) => match validate_input(&content) {
+            Ok(()) => Ok(content),
+            Err(reason) => Err(anyhow!(
+                "Input file is unusable: {} - {}\n\n\
+                To download it again, run:\n    \
+                cargo run --bin aoc download {} {}",
+                reason,
+                from.display(),
+                year,
+                day
+            )),
+        },
+        None => Err(anyhow!(
             "Input file not found - {}\n\n\
             To download it automatically, run:\n    \
             cargo run --bin aoc download {} {}\n\n\
@@ -21,12 +100,73 @@ pub fn load_input(year: u16, day: u8) -> Result<String> {
             path.display(),
             year,
             day
-        ));
+        )),
+    }
+}
+
+// Read a stored input and the file it came from. Encrypted inputs sit next
+// to the plain path as `dayDD.txt.enc`; a plain file is preferred unless it
+// is unusable (an old login page) and an encrypted copy exists.
+fn read_stored(path: &Path) -> Result<Option<(PathBuf, String)>> {
+    let encrypted = encrypted_path(path);
+    if path.exists() {
+        let content = std::fs::read_to_string(path).with_context(|| {
+            format!("Failed to read input file: {}", path.display())
+        })?;
+        if validate_input(&content).is_ok() || !encrypted.exists() {
+            return Ok(Some((path.to_path_buf(), content)));
+        }
     }
-    std::fs::read_to_string(&path)
-        .with_context(|| format!("Failed to read input file: {}", path.display()))
+    if encrypted.exists() {
+        let content = load_encrypted(&encrypted)?;
+        return Ok(Some((encrypted, content)));
+    }
+    Ok(None)
+}
+
+// Decrypt an encrypted input with the configured key
+fn load_encrypted(path: &Path) -> Result<String> {
+    let key = InputKey::load()?.ok_or_else(|| {
+        anyhow!(
+            "Input is encrypted - {}\n\n\
+            Set AOC_INPUT_KEY or put the key in ~/.config/aoc/input.key to read it.",
+            path.display()
+        )
+    })?;
+    let data = std::fs::read(path).with_context(|| {
+        format!("Failed to read input file: {}", path.display())
+    })?;
+    let plaintext = key
+        .decrypt(&data)
+        .with_context(|| format!("Failed to decrypt {}", path.display()))?;
+    String::from_utf8(plaintext).with_context(|| {
+        format!("Decrypted input is not UTF-8: {}", path.display())
+    })
 }
 
+// Load input from an explicit source, falling back to the cached file
+pub fn load_input_from(
+    year: u16,
+    day: u8,
+    source: &InputSource,
+) -> Result<String> {
+    match source {
+        InputSource::Default => load_input(year, day),
+        InputSource::Example(example) => load_example(year, day, *example),
+        InputSource::File(path) => {
+            std::fs::read_to_string(path).with_context(|| {
+                format!("Failed to read input file: {}", path.display())
+            })
+        }
+        InputSource::Stdin => {
+            let mut content = String::new();
+            std::io::stdin()
+                .read_to_string(&mut content)
+                .context("Failed to read input from stdin")?;
+            Ok(content)
+        }
+    }
+}
 
 // Load input file as lines
 pub fn load_input_lines(year: u16, day: u8) -> Result<Vec<String>> {
@@ -35,67 +175,107 @@ pub fn load_input_lines(year: u16, day: u8) -> Result<Vec<String>> {
 }
 
 /// Download input from Advent of Code website
-/// Requires AOC_SESSION env var; accepts either raw token or "session=<token>"
+/// Requires a session token (see `session::resolve_session`)
 pub fn download_input(year: u16, day: u8) -> Result<String> {
-    // basic day guard
-    if day == 0 || day > 25 {
-        return Err(anyhow!("Day must be between 1 and 25"));
-    }
+    AocClient::from_env()?.download_input(year, day)
+}
 
-    let session = std::env::var("AOC_SESSION")
-        .context("AOC_SESSION environment variable not set")?;
-    // allow both formats
-    let session = session.strip_prefix("session=").unwrap_or(&session);
-
-    let url = format!("https://adventofcode.com/{}/day/{}/input", year, day);
-    let client = reqwest::blocking::Client::builder()
-        .user_agent("github.com/sanctusgee/aoc-rust (Rust reqwest)")
-        .build()
-        .context("Failed to build HTTP client")?;
-
-    let response = client
-        .get(&url)
-        .header("Cookie", format!("session={}", session))
-        .send()
-        .context("Failed to send request to AoC")?;
-
-    if !response.status().is_success() {
-        anyhow::bail!("Failed to download input: HTTP {}", response.status());
-    }
+/// Submit an answer for one part of a puzzle
+/// Requires a session token (see `session::resolve_session`)
+pub fn submit_answer(
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<SubmitOutcome> {
+    AocClient::from_env()?.submit_answer(year, day, part, answer)
+}
 
-    let text = response.text().context("Failed to read response text")?;
+/// Read a day's cached input if it is usable. Empty or HTML files (a
+/// crash mid-write, an expired session's login page) are reported and
+/// treated as missing so they get downloaded again.
+pub fn cached_input(year: u16, day: u8) -> Result<Option<String>> {
+    read_cached(year, day, true)
+}
 
-    // detect empty or HTML login page
-    if text.trim().is_empty() || text.trim_start().starts_with("<!DOCTYPE") {
-        anyhow::bail!(
-            "Downloaded empty or HTML content. Verify AOC_SESSION token and puzzle availability."
-        );
-    }
+fn read_cached(year: u16, day: u8, report: bool) -> Result<Option<String>> {
+    let Some((path, content)) = read_stored(&get_input_path(year, day)?)?
+    else {
+        return Ok(None);
+    };
 
-    Ok(text)
+    match validate_input(&content) {
+        Ok(()) => Ok(Some(content)),
+        Err(reason) => {
+            if report {
+                eprintln!(
+                    "{}",
+                    format!(
+                        "Ignoring cached {}: {}; downloading it again",
+                        path.display(),
+                        reason
+                    )
+                    .yellow()
+                );
+            }
+            Ok(None)
+        }
+    }
 }
 
 /// Download and cache input file
 pub fn ensure_input(year: u16, day: u8) -> Result<String> {
-    let path = get_input_path(year, day);
-
-    // If file exists, read it
-    if path.exists() {
-        return load_input(year, day);
+    // If a valid file exists, read it without building a client
+    if let Some(content) = cached_input(year, day)? {
+        return Ok(content);
     }
 
-    // Otherwise, download it
-    let content = download_input(year, day)?;
+    ensure_input_with(&AocClient::from_env()?, year, day)
+}
+
+/// Download and cache input file through an existing client,
+/// so a batch of downloads shares one client and its throttling
+/// (callers check `cached_input` first, which reports a bad cached file)
+pub fn ensure_input_with(
+    client: &AocClient,
+    year: u16,
+    day: u8,
+) -> Result<String> {
+    if let Some(content) = read_cached(year, day, false)? {
+        return Ok(content);
+    }
 
-    // Create directory if needed
-    if let Some(parent) = path.parent() {
-        std::fs::create_dir_all(parent)
-            .context("Failed to create input directory")?;
+    // Concurrent runs for the same day take turns; whoever waited
+    // finds the file the first one wrote
+    let path = get_input_path(year, day)?;
+    let _lock = FileLock::acquire(&path)?;
+    if let Some(content) = read_cached(year, day, false)? {
+        return Ok(content);
     }
 
-    // Save to file
-    std::fs::write(&path, &content)
-        .with_context(|| format!("Failed to write input to {}", path.display()))?;
+    let content = client.download_input(year, day)?;
+
+    // Temp file plus rename: a crash never leaves a truncated input behind.
+    // With an input key configured, only the encrypted copy is stored.
+    match InputKey::load()? {
+        Some(key) => {
+            let encrypted = encrypted_path(&path);
+            write_atomic(&encrypted, key.encrypt(content.as_bytes())?)
+                .with_context(|| {
+                    format!("Failed to write input to {}", encrypted.display())
+                })?;
+            // A plain file here was unusable, or it would have been read
+            // above; left behind it would shadow the new copy
+            if path.exists() {
+                std::fs::remove_file(&path).with_context(|| {
+                    format!("Failed to remove {}", path.display())
+                })?;
+            }
+        }
+        None => write_atomic(&path, &content).with_context(|| {
+            format!("Failed to write input to {}", path.display())
+        })?,
+    }
 
     Ok(content)
 }
@@ -120,14 +300,19 @@ where
                 anyhow::bail!("Line {} has invalid format", i + 1);
             }
 
-            let first = parts[0].trim().parse::<T>()
-                .context(format!("Failed to parse first part on line {}", i + 1))?;
+            let first = parts[0].trim().parse::<T>().context(format!(
+                "Failed to parse first part on line {}",
+                i + 1
+            ))?;
 
             let second = parts[1]
                 .split_whitespace()
                 .map(|s| s.parse::<U>())
                 .collect::<Result<Vec<_>, _>>()
-                .context(format!("Failed to parse second part on line {}", i + 1))?;
+                .context(format!(
+                    "Failed to parse second part on line {}",
+                    i + 1
+                ))?;
 
             Ok((first, second))
         })
@@ -1,10 +1,36 @@
+pub mod answers;
+pub mod cache;
+pub mod calendar;
+pub mod client;
+pub mod config;
+pub mod crypto;
+pub mod history;
 pub mod input;
 pub mod output;
+pub mod paths;
+pub mod puzzle;
+pub mod session;
+pub mod testing;
+pub mod throttle;
 
 // Re-export commonly used items
+pub use client::{
+    aoc_base_url, parse_submit_response, parse_user, user_agent, AnswerHint,
+    AocClient, AocUser, HttpResponse, ReqwestTransport, SubmitOutcome,
+    Transport,
+};
 pub use input::{
-    download_input, ensure_input, get_input_path, load_input, load_input_lines,
-    parse_lines, parse_lines_with_delimiter,
+    cached_input, download_input, ensure_input, ensure_input_with,
+    get_example_path, get_input_path, load_example, load_input,
+    load_input_from, load_input_lines, parse_lines, parse_lines_with_delimiter,
+    submit_answer, InputSource,
 };
-pub use output::SolutionOutput;
-
+pub use output::{
+    format_duration, format_remaining, PartOutcome, RunSummary, SolutionOutput,
+    SummaryRow,
+};
+pub use puzzle::{
+    extract_examples, get_puzzle_path, load_puzzle, puzzle_title,
+    render_puzzle, PageSource, PuzzleExamples, PuzzlePage,
+};
+pub use throttle::{RequestLog, RetryPolicy};
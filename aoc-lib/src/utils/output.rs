@@ -51,19 +51,213 @@ impl SolutionOutput {
         }
 
         if let Some(elapsed) = self.elapsed {
-            let time_str = if elapsed.as_secs() > 0 {
-                format!("{:.2}s", elapsed.as_secs_f64())
-            } else if elapsed.as_millis() > 0 {
-                format!("{}ms", elapsed.as_millis())
-            } else {
-                format!("{}μs", elapsed.as_micros())
-            };
+            let time_str = format_duration(elapsed);
             println!("{} {}", "Time:".bright_black(), time_str.bright_black());
         }
         println!();
     }
 }
 
+/// Format a duration with the most readable unit (s, ms or μs)
+pub fn format_duration(elapsed: Duration) -> String {
+    if elapsed.as_secs() > 0 {
+        format!("{:.2}s", elapsed.as_secs_f64())
+    } else if elapsed.as_millis() > 0 {
+        format!("{}ms", elapsed.as_millis())
+    } else {
+        format!("{}μs", elapsed.as_micros())
+    }
+}
+
+/// Format a wait or countdown as "1h 2m 3s", dropping leading zero units
+pub fn format_remaining(remaining: Duration) -> String {
+    let secs = remaining.as_secs();
+    let (d, h, m, s) = (
+        secs / 86_400,
+        secs % 86_400 / 3600,
+        secs % 3600 / 60,
+        secs % 60,
+    );
+    if d > 0 {
+        format!("{}d {}h {}m {}s", d, h, m, s)
+    } else if h > 0 {
+        format!("{}h {}m {}s", h, m, s)
+    } else if m > 0 {
+        format!("{}m {}s", m, s)
+    } else {
+        format!("{}s", s)
+    }
+}
+
+/// Outcome of one part in a multi-day run
+pub enum PartOutcome {
+    Solved { answer: String, elapsed: Duration },
+    Failed(String),
+    NotRun,
+}
+
+/// One row of a multi-day run: a day's parts, or the error that stopped it
+pub struct SummaryRow {
+    pub year: u16,
+    pub day: u8,
+    pub part1: PartOutcome,
+    pub part2: PartOutcome,
+    pub error: Option<String>,
+}
+
+impl SummaryRow {
+    pub fn new(year: u16, day: u8) -> Self {
+        Self {
+            year,
+            day,
+            part1: PartOutcome::NotRun,
+            part2: PartOutcome::NotRun,
+            error: None,
+        }
+    }
+
+    pub fn failed(year: u16, day: u8, error: String) -> Self {
+        Self {
+            error: Some(error),
+            ..Self::new(year, day)
+        }
+    }
+
+    fn is_failure(&self) -> bool {
+        self.error.is_some()
+            || matches!(self.part1, PartOutcome::Failed(_))
+            || matches!(self.part2, PartOutcome::Failed(_))
+    }
+}
+
+/// Summary table printed after running a whole year or the whole workspace
+pub struct RunSummary {
+    rows: Vec<SummaryRow>,
+}
+
+impl RunSummary {
+    pub fn new() -> Self {
+        Self { rows: Vec::new() }
+    }
+
+    pub fn push(&mut self, row: SummaryRow) {
+        self.rows.push(row);
+    }
+
+    pub fn has_failures(&self) -> bool {
+        self.rows.iter().any(SummaryRow::is_failure)
+    }
+
+    pub fn print(&self) {
+        let header = ["Year", "Day", "Part 1", "Time", "Part 2", "Time"];
+        let mut total = Duration::ZERO;
+
+        let cells: Vec<[String; 6]> = self
+            .rows
+            .iter()
+            .map(|row| {
+                let (p1, t1) = part_cells(&row.part1, &mut total);
+                let (p2, t2) = part_cells(&row.part2, &mut total);
+                let p1 = match &row.error {
+                    Some(err) => format!("error: {}", first_line(err)),
+                    None => p1,
+                };
+                [row.year.to_string(), row.day.to_string(), p1, t1, p2, t2]
+            })
+            .collect();
+
+        let mut widths = header.map(|h| h.chars().count());
+        for row in &cells {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let line = |cols: &[String]| {
+            cols.iter()
+                .zip(widths)
+                .map(|(c, w)| format!("{:<w$}", c, w = w))
+                .collect::<Vec<_>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        };
+
+        let header = header.map(String::from);
+        println!("{}", line(&header).bright_cyan().bold());
+        let rule_len = widths.iter().sum::<usize>() + 2 * (widths.len() - 1);
+        println!("{}", "─".repeat(rule_len).bright_black());
+
+        for (row, cols) in self.rows.iter().zip(&cells) {
+            let text = line(cols);
+            if row.is_failure() {
+                println!("{}", text.bright_red());
+            } else {
+                println!("{}", text);
+            }
+        }
+
+        println!("{}", "─".repeat(rule_len).bright_black());
+        let failed = self.rows.iter().filter(|r| r.is_failure()).count();
+        println!(
+            "{} {} day(s), {} failed, total time {}",
+            "Total:".bright_green(),
+            self.rows.len(),
+            failed,
+            format_duration(total)
+        );
+
+        // Full error messages, which are cut to one line in the table
+        for row in self.rows.iter().filter(|r| r.is_failure()) {
+            let errors = [
+                row.error.as_deref(),
+                failure_message(&row.part1),
+                failure_message(&row.part2),
+            ];
+            for err in errors.into_iter().flatten() {
+                println!();
+                println!(
+                    "{}",
+                    format!("Year {} day {}:", row.year, row.day).bright_red()
+                );
+                println!("{}", err);
+            }
+        }
+        println!();
+    }
+}
+
+impl Default for RunSummary {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Answer and time cells for one part, adding its time to the running total
+fn part_cells(outcome: &PartOutcome, total: &mut Duration) -> (String, String) {
+    match outcome {
+        PartOutcome::Solved { answer, elapsed } => {
+            *total += *elapsed;
+            (first_line(answer).to_string(), format_duration(*elapsed))
+        }
+        PartOutcome::Failed(err) => {
+            (format!("error: {}", first_line(err)), "-".to_string())
+        }
+        PartOutcome::NotRun => ("-".to_string(), "-".to_string()),
+    }
+}
+
+fn failure_message(outcome: &PartOutcome) -> Option<&str> {
+    match outcome {
+        PartOutcome::Failed(err) => Some(err),
+        _ => None,
+    }
+}
+
+fn first_line(text: &str) -> &str {
+    text.lines().next().unwrap_or("")
+}
+
 // Helper macro for timing a block of code
 #[macro_export]
 macro_rules! timed {
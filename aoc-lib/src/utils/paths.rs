@@ -0,0 +1,202 @@
+// `aoc-lib/src/utils/paths.rs`
+
+use crate::utils::config::Config;
+use anyhow::{bail, Result};
+use colored::*;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Where inputs go under the input root unless configured otherwise
+pub const DEFAULT_INPUT_LAYOUT: &str = "year{year}/day{day}.txt";
+
+const LAYOUT_PLACEHOLDERS: [&str; 3] = ["{year}", "{day}", "{profile}"];
+
+// Config for path settings; a broken file is reported once and ignored,
+// since paths are needed even by commands that never touch the network
+pub(crate) fn settings() -> &'static Config {
+    static SETTINGS: OnceLock<Config> = OnceLock::new();
+    SETTINGS.get_or_init(|| {
+        Config::load().unwrap_or_else(|err| {
+            eprintln!(
+                "{}",
+                format!("Warning: ignoring config file: {:#}", err).yellow()
+            );
+            Config::default()
+        })
+    })
+}
+
+/// The workspace root: the nearest ancestor of the current directory that
+/// is this workspace, else the workspace aoc-lib was built from. Inputs and
+/// answers resolve from here, so commands and tests work from any directory.
+pub fn workspace_root() -> PathBuf {
+    static ROOT: OnceLock<PathBuf> = OnceLock::new();
+    ROOT.get_or_init(find_workspace_root).clone()
+}
+
+fn find_workspace_root() -> PathBuf {
+    if let Ok(cwd) = std::env::current_dir() {
+        if let Some(root) = cwd.ancestors().find(|dir| is_workspace_root(dir)) {
+            return root.to_path_buf();
+        }
+    }
+
+    let built_from = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    if is_workspace_root(&built_from) {
+        return built_from.canonicalize().unwrap_or(built_from);
+    }
+    PathBuf::from(".")
+}
+
+// A Cargo workspace manifest with aoc-lib next to it
+fn is_workspace_root(dir: &Path) -> bool {
+    dir.join("aoc-lib").is_dir()
+        && std::fs::read_to_string(dir.join("Cargo.toml")).is_ok_and(
+            |manifest| {
+                manifest.lines().any(|line| line.trim() == "[workspace]")
+            },
+        )
+}
+
+// Relative paths are taken from the workspace root, not the current directory
+pub(crate) fn from_root(path: impl Into<PathBuf>) -> PathBuf {
+    let path = path.into();
+    if path.is_absolute() {
+        path
+    } else {
+        workspace_root().join(path)
+    }
+}
+
+/// Root directory for puzzle inputs: AOC_INPUT_DIR, then `input_dir` in the
+/// config file, then `input/` in the workspace root
+pub fn input_root() -> PathBuf {
+    match std::env::var_os("AOC_INPUT_DIR").filter(|dir| !dir.is_empty()) {
+        Some(dir) => from_root(dir),
+        None => from_root(
+            settings()
+                .input_dir
+                .clone()
+                .unwrap_or_else(|| PathBuf::from("input")),
+        ),
+    }
+}
+
+/// Root directory for recorded answers and submission history
+pub fn answers_root() -> PathBuf {
+    from_root("answers")
+}
+
+/// Layout of input files under the input root: AOC_INPUT_LAYOUT, then
+/// `input_layout` in the config file, then `DEFAULT_INPUT_LAYOUT`.
+/// An invalid layout is reported once and replaced by the default.
+pub fn input_layout() -> &'static str {
+    static LAYOUT: OnceLock<String> = OnceLock::new();
+    LAYOUT.get_or_init(|| {
+        let layout = std::env::var("AOC_INPUT_LAYOUT")
+            .ok()
+            .filter(|layout| !layout.trim().is_empty())
+            .or_else(|| settings().input_layout.clone());
+
+        match layout {
+            Some(layout) => match validate_layout(&layout) {
+                Ok(()) => layout,
+                Err(err) => {
+                    eprintln!(
+                        "{}",
+                        format!(
+                            "Warning: {:#}; using \"{}\"",
+                            err, DEFAULT_INPUT_LAYOUT
+                        )
+                        .yellow()
+                    );
+                    DEFAULT_INPUT_LAYOUT.to_string()
+                }
+            },
+            None => DEFAULT_INPUT_LAYOUT.to_string(),
+        }
+    })
+}
+
+/// Check an input layout: a relative path using `{year}` and `{day}`,
+/// optionally `{profile}`, and no other placeholders
+pub fn validate_layout(layout: &str) -> Result<()> {
+    if Path::new(layout).is_absolute() {
+        bail!(
+            "Input layout \"{}\" must be relative to the input root",
+            layout
+        );
+    }
+    for required in ["{year}", "{day}"] {
+        if !layout.contains(required) {
+            bail!("Input layout \"{}\" must contain {}", layout, required);
+        }
+    }
+
+    let mut rest = layout;
+    while let Some(start) = rest.find('{') {
+        let end = rest[start..].find('}').map(|end| start + end + 1);
+        let placeholder = &rest[start..end.unwrap_or(rest.len())];
+        if !LAYOUT_PLACEHOLDERS.contains(&placeholder) {
+            bail!(
+                "Input layout \"{}\" has unknown placeholder {}; use {}",
+                layout,
+                placeholder,
+                LAYOUT_PLACEHOLDERS.join(", ")
+            );
+        }
+        rest = &rest[end.unwrap_or(rest.len())..];
+    }
+    Ok(())
+}
+
+/// Fill in a layout: `{year}` is e.g. 2024, `{day}` is zero-padded (05),
+/// `{profile}` is the profile name or "default"
+pub fn render_layout(
+    layout: &str,
+    year: u16,
+    day: u8,
+    profile: Option<&str>,
+) -> PathBuf {
+    PathBuf::from(
+        layout
+            .replace("{year}", &year.to_string())
+            .replace("{day}", &format!("{:02}", day))
+            .replace("{profile}", profile.unwrap_or("default")),
+    )
+}
+
+/// Whether `relative` (a path under the input root, `/`-separated) is an
+/// input file name the layout produces: `{year}` four digits, `{day}` two,
+/// `{profile}` a profile name
+pub fn layout_matches(layout: &str, relative: &str) -> bool {
+    if let Some(rest) = layout.strip_prefix("{year}") {
+        return digits_then(relative, 4, |text| layout_matches(rest, text));
+    }
+    if let Some(rest) = layout.strip_prefix("{day}") {
+        return digits_then(relative, 2, |text| layout_matches(rest, text));
+    }
+    if let Some(rest) = layout.strip_prefix("{profile}") {
+        let name_len = relative
+            .find(|c: char| {
+                !(c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            })
+            .unwrap_or(relative.len());
+        return (1..=name_len)
+            .any(|len| layout_matches(rest, &relative[len..]));
+    }
+    match (layout.chars().next(), relative.chars().next()) {
+        (None, None) => true,
+        (Some(l), Some(r)) if l == r => {
+            layout_matches(&layout[l.len_utf8()..], &relative[r.len_utf8()..])
+        }
+        _ => false,
+    }
+}
+
+// `count` ASCII digits at the start of `text`, then the rest matches
+fn digits_then(text: &str, count: usize, rest: impl Fn(&str) -> bool) -> bool {
+    text.len() >= count
+        && text.as_bytes()[..count].iter().all(u8::is_ascii_digit)
+        && rest(&text[count..])
+}
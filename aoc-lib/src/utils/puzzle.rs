@@ -0,0 +1,515 @@
+// `aoc-lib/src/utils/puzzle.rs`
+
+use crate::utils::answers::AnswerStore;
+use crate::utils::cache::write_atomic;
+use crate::utils::client::{strip_tags, AocClient};
+use crate::utils::history::SubmissionHistory;
+use crate::utils::input::get_input_path;
+use anyhow::{bail, Context, Result};
+use colored::*;
+use std::path::{Path, PathBuf};
+
+// Paragraphs are wrapped to this many columns; code blocks never are
+const WRAP_WIDTH: usize = 80;
+
+// Get the path to a day's cached puzzle page, next to its input:
+// `input/yearYYYY/dayDD.txt` -> `input/yearYYYY/dayDD.html`
+pub fn get_puzzle_path(year: u16, day: u8) -> Result<PathBuf> {
+    Ok(get_input_path(year, day)?.with_extension("html"))
+}
+
+/// Where `load_puzzle` got the page from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PageSource {
+    /// Fetched from the site just now
+    Downloaded,
+    /// Read from the cache without a request
+    Cached,
+    /// The fetch failed, so the cached copy is shown; holds the error
+    Offline(String),
+}
+
+/// A puzzle page and where it came from
+#[derive(Debug, Clone)]
+pub struct PuzzlePage {
+    pub html: String,
+    pub path: PathBuf,
+    pub source: PageSource,
+}
+
+/// Reject a page without a puzzle description, such as an error page
+pub fn validate_puzzle(html: &str) -> Result<()> {
+    if puzzle_articles(html).is_empty() {
+        bail!("page has no puzzle description");
+    }
+    Ok(())
+}
+
+/// Whether the page includes part 2, which the site only sends
+/// to a logged-in user who has solved part 1
+pub fn has_part_two(html: &str) -> bool {
+    html.matches("<article").count() >= 2
+}
+
+/// The title from a puzzle page's `<h2>--- Day 5: Print Queue ---</h2>`
+pub fn puzzle_title(html: &str) -> Option<String> {
+    let (_, rest) = html.split_once("<h2>")?;
+    let (heading, _) = rest.split_once("</h2>")?;
+    let heading = decode_entities(heading);
+    let (_, title) = heading.trim().trim_matches('-').split_once(':')?;
+    Some(title.trim().to_string()).filter(|title| !title.is_empty())
+}
+
+/// Fetch a day's puzzle page through `client` and cache it at `path`
+pub fn cache_puzzle_with(
+    client: &AocClient,
+    path: &Path,
+    year: u16,
+    day: u8,
+) -> Result<String> {
+    let html = client.fetch_puzzle(year, day)?;
+    validate_puzzle(&html).with_context(|| {
+        format!("Unexpected puzzle page for {} day {}", year, day)
+    })?;
+    write_atomic(path, &html).with_context(|| {
+        format!("Failed to write puzzle to {}", path.display())
+    })?;
+    Ok(html)
+}
+
+/// A day's puzzle page, from the cache when it is complete.
+/// The page is fetched again with `refresh`, when nothing usable is
+/// cached, or when the cached copy predates solving part 1 (so part 2
+/// shows up). A failed fetch falls back to the cached copy if any.
+pub fn load_puzzle(year: u16, day: u8, refresh: bool) -> Result<PuzzlePage> {
+    let path = get_puzzle_path(year, day)?;
+    let cached = std::fs::read_to_string(&path)
+        .ok()
+        .filter(|html| validate_puzzle(html).is_ok());
+
+    if let Some(html) = &cached {
+        if !refresh && (has_part_two(html) || !part_one_solved(year, day)) {
+            return Ok(PuzzlePage {
+                html: html.clone(),
+                path,
+                source: PageSource::Cached,
+            });
+        }
+    }
+
+    match AocClient::from_env()
+        .and_then(|client| cache_puzzle_with(&client, &path, year, day))
+    {
+        Ok(html) => Ok(PuzzlePage {
+            html,
+            path,
+            source: PageSource::Downloaded,
+        }),
+        Err(err) => match cached {
+            Some(html) => Ok(PuzzlePage {
+                html,
+                path,
+                source: PageSource::Offline(format!("{:#}", err)),
+            }),
+            None => Err(err),
+        },
+    }
+}
+
+// Part 1 is recorded as solved in the answers file or the submission history
+fn part_one_solved(year: u16, day: u8) -> bool {
+    AnswerStore::load(year).is_ok_and(|store| store.get(day, 1).is_some())
+        || SubmissionHistory::load(year)
+            .is_ok_and(|history| history.is_solved(day, 1))
+}
+
+/// The parts of a puzzle page worth reading: each part's `<article>`,
+/// and the "Your puzzle answer was" lines that follow solved parts
+pub fn puzzle_articles(html: &str) -> Vec<&str> {
+    const ANSWER: &str = "<p>Your puzzle answer was";
+
+    let mut fragments = Vec::new();
+    let mut rest = html;
+    loop {
+        let article = rest.find("<article");
+        let answer = rest.find(ANSWER);
+        let (start, end_tag) = match (article, answer) {
+            (Some(a), Some(b)) if b < a => (b, "</p>"),
+            (Some(a), _) => (a, "</article>"),
+            (None, Some(b)) => (b, "</p>"),
+            (None, None) => break,
+        };
+        let Some(len) = rest[start..].find(end_tag) else {
+            break;
+        };
+        let end = start + len + end_tag.len();
+        fragments.push(&rest[start..end]);
+        rest = &rest[end..];
+    }
+    fragments
+}
+
+/// An example's expected answer and which example input it is for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExampleAnswer {
+    pub answer: String,
+    /// Index into `PuzzleExamples::inputs`
+    pub example: usize,
+}
+
+/// Examples guessed from a puzzle page by `extract_examples`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PuzzleExamples {
+    /// Example inputs in page order, without duplicates
+    pub inputs: Vec<String>,
+    /// The expected example answer for part 1 and part 2
+    pub answers: [Option<ExampleAnswer>; 2],
+}
+
+/// Guess the example inputs and answers from a puzzle page. An example is
+/// a `<pre><code>` block introduced by a paragraph mentioning "example"
+/// (else the first block of part 1); a part's answer is the last
+/// `<code><em>` in its article, for the nearest example above it.
+/// Puzzles that stray from this shape need checking by hand.
+pub fn extract_examples(html: &str) -> PuzzleExamples {
+    let mut examples = PuzzleExamples::default();
+    let articles = puzzle_articles(html)
+        .into_iter()
+        .filter(|fragment| fragment.starts_with("<article"));
+
+    for (part, article) in articles.take(2).enumerate() {
+        let blocks = code_blocks(article);
+        let mut found: Vec<(usize, usize)> = blocks
+            .iter()
+            .filter(|block| block.introduced_as_example)
+            .map(|block| (block.start, examples.add(&block.text)))
+            .collect();
+        if found.is_empty() && part == 0 {
+            if let Some(block) = blocks.first() {
+                found.push((block.start, examples.add(&block.text)));
+            }
+        }
+
+        examples.answers[part] =
+            last_answer(article).map(|(pos, answer)| ExampleAnswer {
+                answer,
+                example: found
+                    .iter()
+                    .rev()
+                    .find(|(start, _)| *start < pos)
+                    .map_or(0, |(_, index)| *index),
+            });
+    }
+
+    if examples.inputs.is_empty() {
+        examples.answers = [None, None];
+    }
+    examples
+}
+
+impl PuzzleExamples {
+    // Index of an example input, adding it if it is new
+    fn add(&mut self, text: &str) -> usize {
+        match self.inputs.iter().position(|input| input == text) {
+            Some(index) => index,
+            None => {
+                self.inputs.push(text.to_string());
+                self.inputs.len() - 1
+            }
+        }
+    }
+}
+
+struct CodeBlock {
+    start: usize,
+    text: String,
+    introduced_as_example: bool,
+}
+
+// Every `<pre><code>` block in an article, as plain text
+fn code_blocks(article: &str) -> Vec<CodeBlock> {
+    const OPEN: &str = "<pre><code>";
+    const CLOSE: &str = "</code></pre>";
+
+    let mut blocks = Vec::new();
+    let mut offset = 0;
+    while let Some(start) = article[offset..].find(OPEN).map(|s| s + offset) {
+        let body_start = start + OPEN.len();
+        let Some(len) = article[body_start..].find(CLOSE) else {
+            break;
+        };
+
+        // The text since the last paragraph opened, usually "For example:"
+        let before = &article[offset..start];
+        let intro = before.rfind("<p>").map_or(before, |p| &before[p..]);
+        blocks.push(CodeBlock {
+            start,
+            text: decode_entities(&strip_tags(
+                &article[body_start..body_start + len],
+                "",
+            )),
+            introduced_as_example: intro.to_lowercase().contains("example"),
+        });
+        offset = body_start + len + CLOSE.len();
+    }
+    blocks
+}
+
+// The last highlighted answer in an article and where it appears
+fn last_answer(article: &str) -> Option<(usize, String)> {
+    let patterns = [
+        ("<code><em>", "</em></code>"),
+        ("<em><code>", "</code></em>"),
+    ];
+    patterns
+        .iter()
+        .filter_map(|(open, close)| {
+            let start = article.rfind(open)?;
+            let body = &article[start + open.len()..];
+            let answer = decode_entities(&body[..body.find(close)?]);
+            let single_value = !answer.contains('<') && !answer.contains('\n');
+            (single_value && !answer.trim().is_empty())
+                .then(|| (start, answer.trim().to_string()))
+        })
+        .max_by_key(|(start, _)| *start)
+}
+
+/// Render a puzzle page as terminal text: headings, wrapped paragraphs,
+/// bullet lists and indented code blocks, with `<em>` and `<code>` styled
+pub fn render_puzzle(html: &str) -> String {
+    let mut renderer = Renderer::default();
+    for fragment in puzzle_articles(html) {
+        renderer.render(fragment);
+    }
+    renderer.finish()
+}
+
+#[derive(Default)]
+struct Renderer {
+    out: String,
+    line: String,
+    // Visible width of `line`, and the indent of continuation lines
+    col: usize,
+    indent: usize,
+    has_text: bool,
+    pending_space: bool,
+    pre: Option<String>,
+    heading: bool,
+    em: usize,
+    code: usize,
+}
+
+impl Renderer {
+    fn render(&mut self, html: &str) {
+        let mut rest = html;
+        while !rest.is_empty() {
+            match rest.find('<') {
+                Some(0) => {
+                    let Some(end) = rest.find('>') else { break };
+                    self.tag(&rest[1..end]);
+                    rest = &rest[end + 1..];
+                }
+                Some(start) => {
+                    self.text(&rest[..start]);
+                    rest = &rest[start..];
+                }
+                None => {
+                    self.text(rest);
+                    break;
+                }
+            }
+        }
+    }
+
+    fn tag(&mut self, tag: &str) {
+        let closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match (name.as_str(), closing) {
+            ("h2", false) => {
+                self.end_block();
+                self.heading = true;
+            }
+            ("h2", true) => {
+                self.heading = false;
+                self.end_block();
+            }
+            ("p" | "ul" | "article", _) => self.end_block(),
+            ("li", false) => {
+                self.end_line();
+                self.line.push_str("  - ");
+                self.col = 4;
+                self.indent = 4;
+            }
+            ("li", true) => self.end_line(),
+            ("pre", false) => {
+                self.end_block();
+                self.pre = Some(String::new());
+            }
+            ("pre", true) => self.end_code_block(),
+            ("br", _) => self.new_line(),
+            ("em", false) => self.em += 1,
+            ("em", true) => self.em = self.em.saturating_sub(1),
+            ("code", false) => self.code += 1,
+            ("code", true) => self.code = self.code.saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    fn text(&mut self, raw: &str) {
+        let text = decode_entities(raw);
+        if let Some(pre) = &mut self.pre {
+            let styled = if self.em > 0 {
+                text.bold().to_string()
+            } else {
+                text
+            };
+            pre.push_str(&styled);
+            return;
+        }
+
+        if text.starts_with(char::is_whitespace) {
+            self.pending_space = true;
+        }
+        for (i, word) in text.split_whitespace().enumerate() {
+            if i > 0 {
+                self.pending_space = true;
+            }
+            self.word(word);
+        }
+        if text.ends_with(char::is_whitespace) {
+            self.pending_space = true;
+        }
+    }
+
+    fn word(&mut self, word: &str) {
+        let width = word.chars().count();
+        if self.pending_space && self.has_text {
+            if self.col + 1 + width > WRAP_WIDTH {
+                self.new_line();
+            } else {
+                self.line.push(' ');
+                self.col += 1;
+            }
+        }
+        self.pending_space = false;
+
+        let styled = if self.heading {
+            word.bright_cyan().bold()
+        } else if self.em > 0 {
+            word.bright_white().bold()
+        } else if self.code > 0 {
+            word.bright_green()
+        } else {
+            word.normal()
+        };
+        self.line.push_str(&styled.to_string());
+        self.col += width;
+        self.has_text = true;
+    }
+
+    // Wrap within the current block
+    fn new_line(&mut self) {
+        self.out.push_str(self.line.trim_end());
+        self.out.push('\n');
+        self.line = " ".repeat(self.indent);
+        self.col = self.indent;
+        self.has_text = false;
+        self.pending_space = false;
+    }
+
+    // Finish the current line, e.g. a list item
+    fn end_line(&mut self) {
+        if self.has_text {
+            self.out.push_str(self.line.trim_end());
+            self.out.push('\n');
+        }
+        self.line.clear();
+        self.col = 0;
+        self.indent = 0;
+        self.has_text = false;
+        self.pending_space = false;
+    }
+
+    // Finish the current block and leave one blank line after it
+    fn end_block(&mut self) {
+        self.end_line();
+        if !self.out.is_empty() && !self.out.ends_with("\n\n") {
+            self.out.push('\n');
+        }
+    }
+
+    fn end_code_block(&mut self) {
+        let Some(code) = self.pre.take() else { return };
+        for line in code.trim_end_matches('\n').lines() {
+            if !line.is_empty() {
+                self.out.push_str("    ");
+                self.out.push_str(line);
+            }
+            self.out.push('\n');
+        }
+        self.end_block();
+    }
+
+    fn finish(mut self) -> String {
+        self.end_line();
+        let text = self.out.trim_end();
+        if text.is_empty() {
+            String::new()
+        } else {
+            format!("{}\n", text)
+        }
+    }
+}
+
+/// Decode the HTML entities found in puzzle pages: the named ones the
+/// site uses and numeric references; anything else is left as is
+pub fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let decoded = rest.find(';').filter(|&end| end <= 10).and_then(|end| {
+            let entity = &rest[1..end];
+            let c = match entity {
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "amp" => Some('&'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                _ => entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .or_else(|| {
+                        entity
+                            .strip_prefix('#')
+                            .and_then(|dec| dec.parse().ok())
+                    })
+                    .and_then(char::from_u32),
+            }?;
+            Some((c, end))
+        });
+
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
@@ -0,0 +1,212 @@
+// `aoc-lib/src/utils/session.rs`
+
+use anyhow::{bail, Context, Result};
+use std::fmt;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+// Set once by the CLI from `--session` / `--profile`
+static CLI_SESSION: OnceLock<String> = OnceLock::new();
+static CLI_PROFILE: OnceLock<String> = OnceLock::new();
+
+/// Use this token ahead of every other source (the `--session` flag)
+pub fn set_cli_session(token: impl Into<String>) {
+    let _ = CLI_SESSION.set(token.into());
+}
+
+/// Select a named profile ahead of AOC_PROFILE (the `--profile` flag)
+pub fn set_cli_profile(profile: impl Into<String>) -> Result<()> {
+    let profile = profile.into();
+    validate_profile(&profile)?;
+    let _ = CLI_PROFILE.set(profile);
+    Ok(())
+}
+
+/// The selected profile: `--profile`, then AOC_PROFILE.
+/// `None` is the default profile; "default" selects it explicitly.
+/// Fails if AOC_PROFILE is not a valid profile name, since the name
+/// becomes part of input, answer and session paths.
+pub fn active_profile() -> Result<Option<String>> {
+    let profile = CLI_PROFILE
+        .get()
+        .cloned()
+        .or_else(|| std::env::var("AOC_PROFILE").ok())
+        .map(|p| p.trim().to_string())
+        .filter(|p| !p.is_empty() && p != "default");
+    if let Some(profile) = &profile {
+        validate_profile(profile)?;
+    }
+    Ok(profile)
+}
+
+// Profile names become directory names, so keep them plain
+pub(crate) fn validate_profile(profile: &str) -> Result<()> {
+    let valid = !profile.is_empty()
+        && profile
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid {
+        bail!(
+            "Invalid profile name \"{}\": use letters, digits, '-' and '_'",
+            profile
+        );
+    }
+    Ok(())
+}
+
+/// Directory for per-user settings: AOC_CONFIG_DIR, else `~/.config/aoc`
+pub fn config_dir() -> Result<PathBuf> {
+    if let Ok(dir) = std::env::var("AOC_CONFIG_DIR") {
+        return Ok(PathBuf::from(dir));
+    }
+    if let Ok(dir) = std::env::var("XDG_CONFIG_HOME") {
+        return Ok(PathBuf::from(dir).join("aoc"));
+    }
+    let home = std::env::var("HOME")
+        .or_else(|_| std::env::var("USERPROFILE"))
+        .context("Cannot locate the home directory; set AOC_CONFIG_DIR")?;
+    Ok(PathBuf::from(home).join(".config").join("aoc"))
+}
+
+/// Session file for a profile: `~/.config/aoc/session` for the default
+/// profile, `~/.config/aoc/profiles/<name>` for named ones
+pub fn get_session_path(profile: Option<&str>) -> Result<PathBuf> {
+    let dir = config_dir()?;
+    Ok(match profile {
+        Some(profile) => {
+            validate_profile(profile)?;
+            dir.join("profiles").join(profile)
+        }
+        None => dir.join("session"),
+    })
+}
+
+/// Per-profile data directory: `base` for the default profile,
+/// `base/<profile>` for named ones, so accounts never share files
+pub fn profile_dir(base: &Path) -> Result<PathBuf> {
+    Ok(match active_profile()? {
+        Some(profile) => base.join(profile),
+        None => base.to_path_buf(),
+    })
+}
+
+/// Where a resolved session token came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SessionSource {
+    Flag,
+    Env,
+    File(PathBuf),
+}
+
+impl fmt::Display for SessionSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SessionSource::Flag => write!(f, "--session flag"),
+            SessionSource::Env => write!(f, "AOC_SESSION environment variable"),
+            SessionSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// A session token and where it was found
+#[derive(Debug, Clone)]
+pub struct ResolvedSession {
+    pub token: String,
+    pub source: SessionSource,
+}
+
+/// Find the session token, in order: `--session`, AOC_SESSION, then the
+/// active profile's session file. `None` when no source has one.
+pub fn resolve_session() -> Result<Option<ResolvedSession>> {
+    if let Some(token) = CLI_SESSION.get() {
+        return Ok(Some(ResolvedSession {
+            token: normalize_session(token),
+            source: SessionSource::Flag,
+        }));
+    }
+
+    if let Some(token) = std::env::var("AOC_SESSION")
+        .ok()
+        .filter(|t| !t.trim().is_empty())
+    {
+        return Ok(Some(ResolvedSession {
+            token: normalize_session(&token),
+            source: SessionSource::Env,
+        }));
+    }
+
+    let path = get_session_path(active_profile()?.as_deref())?;
+    if !path.exists() {
+        return Ok(None);
+    }
+    let token = std::fs::read_to_string(&path).with_context(|| {
+        format!("Failed to read session file {}", path.display())
+    })?;
+    let token = normalize_session(&token);
+    if token.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(ResolvedSession {
+        token,
+        source: SessionSource::File(path),
+    }))
+}
+
+/// Save a token as the given profile's session file (owner-only on Unix)
+pub fn save_session(profile: Option<&str>, token: &str) -> Result<PathBuf> {
+    let token = normalize_session(token);
+    if token.is_empty() {
+        bail!("Refusing to save an empty session token");
+    }
+
+    let path = get_session_path(profile)?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).with_context(|| {
+            format!("Failed to create {}", parent.display())
+        })?;
+    }
+
+    // Owner-only from creation, so the token is never readable by others,
+    // even briefly; an existing file keeps its mode, so restrict it too
+    // before the token goes in
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let mut file = options
+        .open(&path)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))
+            .with_context(|| {
+                format!("Failed to restrict permissions on {}", path.display())
+            })?;
+    }
+    file.write_all(format!("{}\n", token).as_bytes())
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+
+    Ok(path)
+}
+
+/// Shorten a token for display: first and last four characters
+pub fn mask_token(token: &str) -> String {
+    let chars: Vec<char> = token.chars().collect();
+    if chars.len() <= 12 {
+        return "*".repeat(chars.len());
+    }
+    let head: String = chars[..4].iter().collect();
+    let tail: String = chars[chars.len() - 4..].iter().collect();
+    format!("{}…{}", head, tail)
+}
+
+/// Accept either raw token or "session=<token>"
+pub fn normalize_session(session: &str) -> String {
+    let session = session.trim();
+    session
+        .strip_prefix("session=")
+        .unwrap_or(session)
+        .to_string()
+}
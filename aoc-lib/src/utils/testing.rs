@@ -0,0 +1,90 @@
+// `aoc-lib/src/utils/testing.rs`
+
+use anyhow::Result;
+use std::fmt::Display;
+
+/// Generate an example test for one part of a day.
+///
+/// `aoc_example_test!(part1, EXAMPLE, 11)` expands to a `part1::example` test
+/// that runs `part1(EXAMPLE)` and compares it with `11`. Use it inside the
+/// day's `#[cfg(test)]` module, after `use super::*;`.
+///
+/// For more examples of the same part, name each test:
+/// `aoc_example_test!(part1, larger_example, EXAMPLE_2, 2028)` expands to a
+/// `larger_example` test.
+#[macro_export]
+macro_rules! aoc_example_test {
+    ($part:ident, $name:ident, $input:expr, $expected:expr) => {
+        #[test]
+        fn $name() {
+            $crate::utils::testing::assert_example(
+                stringify!($part),
+                $part($input),
+                $expected,
+            );
+        }
+    };
+    ($part:ident, $input:expr, $expected:expr) => {
+        mod $part {
+            use super::*;
+
+            #[test]
+            fn example() {
+                $crate::utils::testing::assert_example(
+                    stringify!($part),
+                    $part($input),
+                    $expected,
+                );
+            }
+        }
+    };
+}
+
+/// Compare a part's answer on an example with the expected one,
+/// panicking with an expected vs actual diff when they differ
+#[track_caller]
+pub fn assert_example<E: Display>(
+    part: &str,
+    actual: Result<String>,
+    expected: E,
+) {
+    let actual = actual.unwrap_or_else(|err| {
+        panic!("{} failed on the example: {:#}", part, err)
+    });
+    let expected = expected.to_string();
+
+    if actual.trim() != expected.trim() {
+        panic!("{}", example_diff(part, expected.trim(), actual.trim()));
+    }
+}
+
+// Single-line answers side by side; multi-line answers line by line,
+// marking the lines that differ with `-` (expected) and `+` (actual)
+fn example_diff(part: &str, expected: &str, actual: &str) -> String {
+    let mut out = format!("{} answer does not match the example\n", part);
+
+    if !expected.contains('\n') && !actual.contains('\n') {
+        out.push_str(&format!("  expected: {}\n", expected));
+        out.push_str(&format!("    actual: {}\n", actual));
+        return out;
+    }
+
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+    let len = expected_lines.len().max(actual_lines.len());
+
+    for i in 0..len {
+        match (expected_lines.get(i), actual_lines.get(i)) {
+            (Some(e), Some(a)) if e == a => out.push_str(&format!("  {}\n", e)),
+            (e, a) => {
+                if let Some(e) = e {
+                    out.push_str(&format!("- {}\n", e));
+                }
+                if let Some(a) = a {
+                    out.push_str(&format!("+ {}\n", a));
+                }
+            }
+        }
+    }
+    out
+}
@@ -0,0 +1,165 @@
+// `aoc-lib/src/utils/throttle.rs`
+
+use crate::utils::session::config_dir;
+use anyhow::{Context, Result};
+use colored::*;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// Keep the log from growing forever; only the last entry matters for pacing
+const MAX_LOG_LINES: usize = 1000;
+
+// Get the path to the request log: `~/.config/aoc/requests.log`
+pub fn get_request_log_path() -> Result<PathBuf> {
+    Ok(config_dir()?.join("requests.log"))
+}
+
+/// Persistent log of requests sent to the site, shared by every `aoc`
+/// invocation, so consecutive commands keep at least `min_interval` apart.
+///
+/// One line per request: `<unix millis> <METHOD> <status> <url>`
+#[derive(Debug, Clone)]
+pub struct RequestLog {
+    path: PathBuf,
+    min_interval: Duration,
+}
+
+impl RequestLog {
+    pub fn new(path: impl Into<PathBuf>, min_interval: Duration) -> Self {
+        Self {
+            path: path.into(),
+            min_interval,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn min_interval(&self) -> Duration {
+        self.min_interval
+    }
+
+    /// Time of the most recent logged request, as unix millis
+    pub fn last_request(&self) -> Option<u128> {
+        let content = std::fs::read_to_string(&self.path).ok()?;
+        content
+            .lines()
+            .rev()
+            .find_map(|line| line.split_whitespace().next()?.parse().ok())
+    }
+
+    /// How long to wait before the next request may be sent
+    pub fn wait_needed(&self) -> Duration {
+        let Some(last) = self.last_request() else {
+            return Duration::ZERO;
+        };
+        let elapsed =
+            Duration::from_millis(now_millis().saturating_sub(last) as u64);
+        self.min_interval.saturating_sub(elapsed)
+    }
+
+    /// Sleep until the minimum interval since the last request has passed
+    pub fn wait_turn(&self) {
+        let wait = self.wait_needed();
+        if !wait.is_zero() {
+            eprintln!(
+                "{}",
+                format!(
+                    "Waiting {:.1}s between requests to AoC",
+                    wait.as_secs_f64()
+                )
+                .bright_black()
+            );
+            std::thread::sleep(wait);
+        }
+    }
+
+    /// Append a request to the log; `status` is `None` if it never got a response
+    pub fn record(
+        &self,
+        method: &str,
+        url: &str,
+        status: Option<u16>,
+    ) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).with_context(|| {
+                format!("Failed to create {}", parent.display())
+            })?;
+        }
+        let status = status
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| "error".to_string());
+
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| {
+                format!("Failed to open {}", self.path.display())
+            })?;
+        writeln!(file, "{} {} {} {}", now_millis(), method, status, url)
+            .with_context(|| {
+                format!("Failed to write {}", self.path.display())
+            })?;
+        drop(file);
+
+        self.truncate()
+    }
+
+    // Drop the oldest half once the log passes MAX_LOG_LINES
+    fn truncate(&self) -> Result<()> {
+        let content =
+            std::fs::read_to_string(&self.path).with_context(|| {
+                format!("Failed to read {}", self.path.display())
+            })?;
+        let lines: Vec<&str> = content.lines().collect();
+        if lines.len() <= MAX_LOG_LINES {
+            return Ok(());
+        }
+
+        let kept = lines[lines.len() - MAX_LOG_LINES / 2..].join("\n");
+        std::fs::write(&self.path, kept + "\n")
+            .with_context(|| format!("Failed to write {}", self.path.display()))
+    }
+}
+
+/// Bounded retries with exponential backoff for HTTP 5xx responses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    /// Delay before the first retry; doubled for each one after
+    pub base_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_secs(1),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Never retry
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            base_delay: Duration::ZERO,
+        }
+    }
+
+    /// Delay before retry number `retry` (0-based)
+    pub fn delay(&self, retry: u32) -> Duration {
+        self.base_delay.saturating_mul(2u32.saturating_pow(retry))
+    }
+}
+
+fn now_millis() -> u128 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or(0)
+}
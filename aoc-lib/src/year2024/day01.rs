@@ -1,24 +1,20 @@
-// Auto-generated day stub. Do not delete solve()
-// Add you code to solve(), or implement other fn and call from solve().
+// Auto-generated day stub. Do not delete part1() or part2()
+// Add your code to solve_part1() and solve_part2(); part1() and part2() hand the answers to the runner.
 
 use anyhow::Result;
-use crate::utils;
 
+/// Puzzle title, shown by `aoc list` and searched by `aoc find`
+pub const TITLE: &str = "Historian Hysteria";
 
-// Example template.
-
-pub fn solve() -> Result<()> {
-// Load your input file.
-	let input = utils::load_input(2024, 1)?;
 
-	let part1 = solve_part1(&input)?;
-	let part2 = solve_part2(&input)?;
+// Example template.
 
-	println!("Day 1 / Year 2024");
-	println!("Part 1: {}", part1);
-	println!("Part 2: {}", part2);
+pub fn part1(input: &str) -> Result<String> {
+	solve_part1(input).map(|answer| answer.to_string())
+}
 
-	Ok(())
+pub fn part2(input: &str) -> Result<String> {
+	solve_part2(input).map(|answer| answer.to_string())
 }
 
 // Rename _input variable in fn signature back to input after implementing the solution
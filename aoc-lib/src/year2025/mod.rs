@@ -1,12 +1,15 @@
 // Auto-generated by new-day tool. No edit required
 // Year 2025
 
-use anyhow::Result;
+use crate::solution::DayEntry;
+
+/// Puzzles in this year's event; the last day has no part 2
+pub const DAY_COUNT: u8 = 12;
 
 mod day01;
 
 
-pub const DAYS: &[(&str, fn() -> Result<()>)] =
+pub const DAYS: &[DayEntry] =
 &[
-    ("1", day01::solve),
+    ("1", day01::TITLE, day01::part1, day01::part2),
 ];
@@ -0,0 +1,85 @@
+// aoc-lib/tests/answers.rs
+// Accepted answers: the TOML file they live in and checking fresh answers
+// against them.
+
+mod common;
+
+use aoc_lib::utils::answers::{check_answer, AnswerStore, CheckStatus};
+use common::ScratchDir;
+
+#[test]
+fn answers_round_trip_through_their_file() {
+    let dir = ScratchDir::new("answers-round-trip");
+    let path = dir.join("year2024.toml");
+    let mut store = AnswerStore::load_from(2024, &path).unwrap();
+    assert_eq!(store.get(1, 1), None);
+
+    store.set(1, 1, "1234");
+    store.set(1, 2, "hello world");
+    store.set(12, 2, "-5");
+    // Only parts 1 and 2 exist
+    store.set(1, 3, "ignored");
+    store.save().unwrap();
+
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert!(content.contains("[day01]"), "{}", content);
+    assert!(content.contains("part1 = \"1234\""), "{}", content);
+
+    let loaded = AnswerStore::load_from(2024, &path).unwrap();
+    assert_eq!(loaded.year(), 2024);
+    assert_eq!(loaded.get(1, 1), Some("1234"));
+    assert_eq!(loaded.get(1, 2), Some("hello world"));
+    assert_eq!(loaded.get(12, 1), None);
+    assert_eq!(loaded.get(12, 2), Some("-5"));
+    assert_eq!(loaded.get(1, 3), None);
+}
+
+#[test]
+fn fresh_answers_are_checked_against_the_store() {
+    let dir = ScratchDir::new("answers-check");
+    let path = dir.join("year2024.toml");
+    std::fs::write(&path, "[day05]\npart1 = 42\npart2 = \"abc\"\n").unwrap();
+    let store = AnswerStore::load_from(2024, &path).unwrap();
+
+    assert_eq!(check_answer(store.get(5, 1), "42\n"), CheckStatus::Pass);
+    assert_eq!(check_answer(store.get(5, 2), " abc"), CheckStatus::Pass);
+    assert_eq!(
+        check_answer(store.get(5, 1), "43"),
+        CheckStatus::Fail {
+            expected: "42".to_string()
+        }
+    );
+    assert_eq!(check_answer(store.get(6, 1), "1"), CheckStatus::Missing);
+}
+
+#[test]
+fn answers_must_be_strings_or_integers() {
+    let dir = ScratchDir::new("answers-bad-values");
+    let path = dir.join("year2024.toml");
+
+    for (content, expected) in [
+        (
+            "[day01]\npart1 = 1.5\n",
+            "must be a string or an integer, got float",
+        ),
+        (
+            "[day01]\npart2 = true\n",
+            "must be a string or an integer, got boolean",
+        ),
+        (
+            "[day01]\npart1 = [1, 2]\n",
+            "must be a string or an integer, got array",
+        ),
+        ("[dayone]\npart1 = 1\n", "expected [dayDD]"),
+        ("day01 = 5\n", "must be a table"),
+    ] {
+        std::fs::write(&path, content).unwrap();
+        let err = AnswerStore::load_from(2024, &path).unwrap_err();
+        assert!(
+            format!("{:#}", err).contains(expected),
+            "{:?}: {:#}",
+            content,
+            err
+        );
+    }
+}
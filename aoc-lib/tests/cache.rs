@@ -0,0 +1,107 @@
+// aoc-lib/tests/cache.rs
+// Atomic writes, lock files and cached-input validation, in a scratch directory.
+
+mod common;
+
+use aoc_lib::utils::cache::{validate_input, write_atomic, FileLock};
+use common::ScratchDir;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+#[test]
+fn write_atomic_creates_parents_and_replaces() {
+    let dir = ScratchDir::new("cache-write");
+    let path = dir.join("year2024/day01.txt");
+
+    write_atomic(&path, "first\n").unwrap();
+    write_atomic(&path, "second\n").unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "second\n");
+
+    // Only the file itself is left behind, no temp files
+    let entries = std::fs::read_dir(dir.join("year2024")).unwrap().count();
+    assert_eq!(entries, 1);
+}
+
+#[test]
+fn lock_is_exclusive_and_released_on_drop() {
+    let dir = ScratchDir::new("cache-lock");
+    let path = dir.join("day01.txt");
+    let order = Arc::new(Mutex::new(Vec::new()));
+
+    let lock = FileLock::acquire(&path).unwrap();
+    assert!(lock.path().exists());
+
+    let waiter = {
+        let (path, order) = (path.clone(), Arc::clone(&order));
+        std::thread::spawn(move || {
+            let _lock = FileLock::acquire(&path).unwrap();
+            order.lock().unwrap().push("second");
+        })
+    };
+    std::thread::sleep(Duration::from_millis(300));
+    order.lock().unwrap().push("first");
+    let lock_path = lock.path().to_path_buf();
+    drop(lock);
+    waiter.join().unwrap();
+
+    assert_eq!(*order.lock().unwrap(), ["first", "second"]);
+    assert!(!lock_path.exists());
+}
+
+#[test]
+fn stale_lock_is_taken_over() {
+    let dir = ScratchDir::new("cache-stale");
+    let path = dir.join("day01.txt");
+    std::fs::write(dir.join("day01.txt.lock"), "pid 0\n").unwrap();
+
+    std::thread::sleep(Duration::from_millis(100));
+    let lock =
+        FileLock::acquire_with(&path, Duration::from_millis(50)).unwrap();
+    drop(lock);
+}
+
+#[test]
+fn stale_lock_is_taken_over_by_one_waiter_at_a_time() {
+    let dir = ScratchDir::new("cache-stale-race");
+    let path = dir.join("day01.txt");
+    let stale = std::fs::File::create(dir.join("day01.txt.lock")).unwrap();
+    stale
+        .set_modified(SystemTime::now() - Duration::from_secs(3600))
+        .unwrap();
+
+    let holders = Arc::new(Mutex::new((0, 0)));
+    let waiters: Vec<_> = (0..4)
+        .map(|_| {
+            let (path, holders) = (path.clone(), Arc::clone(&holders));
+            std::thread::spawn(move || {
+                let _lock =
+                    FileLock::acquire_with(&path, Duration::from_secs(5))
+                        .unwrap();
+                {
+                    let mut holders = holders.lock().unwrap();
+                    holders.0 += 1;
+                    holders.1 = holders.1.max(holders.0);
+                }
+                std::thread::sleep(Duration::from_millis(20));
+                holders.lock().unwrap().0 -= 1;
+            })
+        })
+        .collect();
+    for waiter in waiters {
+        waiter.join().unwrap();
+    }
+
+    // Nobody removed a lock someone else had just taken over
+    assert_eq!(holders.lock().unwrap().1, 1);
+}
+
+#[test]
+fn cached_input_must_be_text_input() {
+    assert!(validate_input("1 2 3\n").is_ok());
+    assert!(validate_input("").is_err());
+    assert!(validate_input(" \n\n").is_err());
+    assert!(validate_input("<!DOCTYPE html>\n<html>").is_err());
+    assert!(validate_input("\n<html lang=\"en\">").is_err());
+    // Inputs may legitimately contain '<'
+    assert!(validate_input("<<>>\n").is_ok());
+}
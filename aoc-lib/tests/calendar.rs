@@ -0,0 +1,63 @@
+// aoc-lib/tests/calendar.rs
+// Date arithmetic behind unlock times and each event's day count;
+// no clock or network involved.
+
+use aoc_lib::utils::calendar::{
+    civil_from_days, days_from_civil, default_day_count, ensure_valid_day,
+    format_utc, full_year, is_last_day, unlock_time,
+};
+
+#[test]
+fn civil_dates_round_trip() {
+    for days in [-719_468, -1, 0, 1, 11_016, 19_692, 20_088, 47_482] {
+        let (year, month, day) = civil_from_days(days);
+        assert_eq!(
+            days_from_civil(year, month, day),
+            days,
+            "{}-{}-{}",
+            year,
+            month,
+            day
+        );
+    }
+    assert_eq!(days_from_civil(1970, 1, 1), 0);
+    assert_eq!(days_from_civil(2000, 3, 1), 11_017);
+}
+
+#[test]
+fn puzzles_unlock_at_midnight_est() {
+    assert_eq!(unlock_time(2024, 1), 1_733_029_200);
+    assert_eq!(format_utc(unlock_time(2015, 1)), "2015-12-01 05:00:00 UTC");
+    assert_eq!(format_utc(unlock_time(2025, 12)), "2025-12-12 05:00:00 UTC");
+}
+
+#[test]
+fn events_have_twelve_days_from_2025() {
+    assert_eq!(default_day_count(2015), 25);
+    assert_eq!(default_day_count(2024), 25);
+    assert_eq!(default_day_count(2025), 12);
+    assert_eq!(default_day_count(2030), 12);
+}
+
+#[test]
+fn days_are_checked_against_the_day_count() {
+    // Years without solutions fall back to the default count
+    assert!(ensure_valid_day(2015, 25).is_ok());
+    assert!(ensure_valid_day(2015, 0).is_err());
+    assert!(ensure_valid_day(2030, 12).is_ok());
+
+    let err = ensure_valid_day(2030, 13).unwrap_err().to_string();
+    assert!(err.contains("between 1 and 12"), "{}", err);
+
+    assert!(is_last_day(2015, 25));
+    assert!(is_last_day(2030, 12));
+    assert!(!is_last_day(2030, 11));
+}
+
+#[test]
+fn two_digit_years_are_this_century() {
+    assert_eq!(full_year(24), 2024);
+    assert_eq!(full_year(0), 2000);
+    assert_eq!(full_year(2015), 2015);
+    assert_eq!(full_year(100), 100);
+}
@@ -0,0 +1,322 @@
+// aoc-lib/tests/client_mock.rs
+// AocClient against the bundled mock server: no network, no real session.
+
+use aoc_lib::utils::{
+    user_agent, AnswerHint, AocClient, AocUser, RequestLog, ReqwestTransport,
+    RetryPolicy, SubmitOutcome,
+};
+use mock_aoc::{MockAoc, MockConfig, MockPuzzle};
+use std::time::Duration;
+
+fn server() -> MockAoc {
+    MockAoc::start(MockConfig::default()).expect("start mock server")
+}
+
+fn client(server: &MockAoc, session: Option<&str>) -> AocClient {
+    AocClient::new(
+        server.base_url(),
+        session.map(String::from),
+        Box::new(ReqwestTransport::new().expect("build transport")),
+    )
+}
+
+fn error_of<T: std::fmt::Debug>(result: anyhow::Result<T>) -> String {
+    format!("{:#}", result.expect_err("request should fail"))
+}
+
+#[test]
+fn downloads_input_with_valid_session() {
+    let server = server();
+    let input = client(&server, Some("test-session"))
+        .download_input(2024, 1)
+        .unwrap();
+    assert_eq!(input, "3   4\n4   3\n");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].path, "/2024/day/1/input");
+    assert_eq!(requests[0].session.as_deref(), Some("test-session"));
+}
+
+#[test]
+fn session_cookie_prefix_is_stripped() {
+    let server = server();
+    client(&server, Some("session=test-session"))
+        .download_input(2024, 1)
+        .unwrap();
+    assert_eq!(
+        server.requests()[0].session.as_deref(),
+        Some("test-session")
+    );
+}
+
+#[test]
+fn expired_session_reports_html_login_page() {
+    let server = server();
+    let err = error_of(
+        client(&server, Some("expired-session")).download_input(2024, 1),
+    );
+    assert!(err.contains("empty or HTML content"), "{}", err);
+}
+
+#[test]
+fn empty_input_is_rejected() {
+    let server = MockAoc::start(MockConfig::default().with_puzzle(
+        2024,
+        2,
+        MockPuzzle::new("Empty", "", "1", "2"),
+    ))
+    .unwrap();
+    let err =
+        error_of(client(&server, Some("test-session")).download_input(2024, 2));
+    assert!(err.contains("empty or HTML content"), "{}", err);
+}
+
+#[test]
+fn lowercase_html_input_is_rejected() {
+    let server = MockAoc::start(MockConfig::default().with_puzzle(
+        2024,
+        2,
+        MockPuzzle::new(
+            "Html",
+            "\n<html><body>Log in</body></html>\n",
+            "1",
+            "2",
+        ),
+    ))
+    .unwrap();
+    let err =
+        error_of(client(&server, Some("test-session")).download_input(2024, 2));
+    assert!(err.contains("it is an HTML page"), "{}", err);
+}
+
+#[test]
+fn locked_day_is_http_404() {
+    let server = server();
+    let err = error_of(
+        client(&server, Some("test-session")).download_input(2024, 25),
+    );
+    assert!(err.contains("HTTP 404"), "{}", err);
+    assert!(err.contains("not unlocked yet"), "{}", err);
+}
+
+#[test]
+fn unknown_session_is_http_400() {
+    let server = server();
+    let err = error_of(
+        client(&server, Some("not-a-session")).download_input(2024, 1),
+    );
+    assert!(err.contains("HTTP 400"), "{}", err);
+}
+
+#[test]
+fn missing_session_fails_before_any_request() {
+    let server = server();
+    let err = error_of(client(&server, None).download_input(2024, 1));
+    assert!(err.contains("AOC_SESSION"), "{}", err);
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn fetch_puzzle_reveals_part_two_after_solving() {
+    let server = server();
+    let client = client(&server, Some("test-session"));
+
+    let page = client.fetch_puzzle(2024, 1).unwrap();
+    assert!(page.contains("--- Day 1: Mock Puzzle ---"));
+    assert!(!page.contains("--- Part Two ---"));
+
+    assert_eq!(
+        client.submit_answer(2024, 1, 1, "11").unwrap(),
+        SubmitOutcome::Correct
+    );
+    let page = client.fetch_puzzle(2024, 1).unwrap();
+    assert!(page.contains("--- Part Two ---"));
+    assert!(page.contains("Your puzzle answer was <code>11</code>"));
+}
+
+#[test]
+fn fetch_puzzle_works_without_session() {
+    let server = server();
+    let page = client(&server, None).fetch_puzzle(2024, 1).unwrap();
+    assert!(page.contains("--- Day 1: Mock Puzzle ---"));
+    assert_eq!(server.requests()[0].session, None);
+}
+
+#[test]
+fn submit_wrong_answers_report_hint_and_lockout() {
+    let server = MockAoc::start(MockConfig {
+        answer_cooldown: Duration::ZERO,
+        ..MockConfig::default()
+    })
+    .unwrap();
+    let client = client(&server, Some("test-session"));
+
+    assert_eq!(
+        client.submit_answer(2024, 1, 1, "100").unwrap(),
+        SubmitOutcome::Wrong {
+            hint: Some(AnswerHint::TooHigh),
+            wait: Some(Duration::ZERO),
+        }
+    );
+    assert!(matches!(
+        client.submit_answer(2024, 1, 1, "5").unwrap(),
+        SubmitOutcome::Wrong {
+            hint: Some(AnswerHint::TooLow),
+            ..
+        }
+    ));
+    assert!(server.requests()[0].body.contains("level=1"));
+}
+
+#[test]
+fn submit_inside_lockout_is_rate_limited() {
+    let server = server();
+    let client = client(&server, Some("test-session"));
+
+    assert!(matches!(
+        client.submit_answer(2024, 1, 1, "12").unwrap(),
+        SubmitOutcome::Wrong {
+            wait: Some(wait),
+            ..
+        } if wait == Duration::from_secs(60)
+    ));
+    match client.submit_answer(2024, 1, 1, "11").unwrap() {
+        SubmitOutcome::RateLimited { wait: Some(wait) } => {
+            assert!(wait <= Duration::from_secs(60) && wait > Duration::ZERO)
+        }
+        other => panic!("expected rate limit, got {:?}", other),
+    }
+}
+
+#[test]
+fn submit_solved_part_is_already_solved() {
+    let server = server();
+    let client = client(&server, Some("test-session"));
+
+    assert_eq!(
+        client.submit_answer(2024, 1, 1, "11").unwrap(),
+        SubmitOutcome::Correct
+    );
+    assert_eq!(
+        client.submit_answer(2024, 1, 1, "11").unwrap(),
+        SubmitOutcome::AlreadySolved
+    );
+    assert_eq!(
+        client.submit_answer(2024, 1, 2, "31").unwrap(),
+        SubmitOutcome::Correct
+    );
+}
+
+#[test]
+fn submit_to_locked_day_is_http_404() {
+    let server = server();
+    let err = error_of(
+        client(&server, Some("test-session")).submit_answer(2024, 25, 1, "1"),
+    );
+    assert!(err.contains("HTTP 404"), "{}", err);
+}
+
+#[test]
+fn fetch_user_reports_logged_in_user() {
+    let server = server();
+    let user = client(&server, Some("test-session")).fetch_user().unwrap();
+    assert_eq!(
+        user,
+        Some(AocUser {
+            name: "Mock User".to_string(),
+            stars: Some(0),
+        })
+    );
+    assert_eq!(server.requests()[0].path, "/");
+}
+
+#[test]
+fn fetch_user_is_none_for_expired_session() {
+    let server = server();
+    let user = client(&server, Some("expired-session"))
+        .fetch_user()
+        .unwrap();
+    assert_eq!(user, None);
+}
+
+#[test]
+fn server_errors_are_retried_with_backoff() {
+    let server = server();
+    server.fail_next(2);
+    let client =
+        client(&server, Some("test-session")).with_retry(RetryPolicy {
+            max_retries: 3,
+            base_delay: Duration::from_millis(1),
+        });
+
+    assert_eq!(client.download_input(2024, 1).unwrap(), "3   4\n4   3\n");
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[test]
+fn retries_are_bounded() {
+    let server = server();
+    server.fail_next(5);
+    let client =
+        client(&server, Some("test-session")).with_retry(RetryPolicy {
+            max_retries: 1,
+            base_delay: Duration::from_millis(1),
+        });
+
+    let err = error_of(client.download_input(2024, 1));
+    assert!(err.contains("HTTP 500"), "{}", err);
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn request_log_enforces_minimum_interval() {
+    let server = server();
+    let path = std::env::temp_dir()
+        .join(format!("aoc-requests-{}.log", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let interval = Duration::from_millis(300);
+    let client = client(&server, Some("test-session"))
+        .with_request_log(RequestLog::new(&path, interval));
+
+    let start = std::time::Instant::now();
+    client.download_input(2024, 1).unwrap();
+    client.fetch_puzzle(2024, 1).unwrap();
+    assert!(start.elapsed() >= interval);
+
+    let log = std::fs::read_to_string(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+    let lines: Vec<&str> = log.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(
+        lines[0].contains("GET 200") && lines[0].ends_with("/2024/day/1/input")
+    );
+}
+
+#[test]
+fn user_agent_carries_contact() {
+    let server = server();
+    let transport =
+        ReqwestTransport::with_user_agent(&user_agent(Some("me@example.com")))
+            .unwrap();
+    AocClient::new(server.base_url(), None, Box::new(transport))
+        .fetch_puzzle(2024, 1)
+        .unwrap();
+
+    let agent = server.requests()[0].user_agent.clone().unwrap();
+    assert!(agent.starts_with("aoc-lib/"), "{}", agent);
+    assert!(agent.contains("me@example.com"), "{}", agent);
+}
+
+#[test]
+fn locked_puzzle_is_refused_before_any_request() {
+    let server = server();
+    let client = client(&server, Some("test-session"));
+
+    let err = error_of(client.download_input(2099, 1));
+    assert!(err.contains("not unlocked yet"), "{}", err);
+    assert!(err.contains("2099-12-01 05:00:00 UTC"), "{}", err);
+    let err = error_of(client.submit_answer(2099, 1, 1, "1"));
+    assert!(err.contains("not unlocked yet"), "{}", err);
+    assert!(server.requests().is_empty());
+}
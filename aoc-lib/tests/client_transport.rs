@@ -0,0 +1,247 @@
+// aoc-lib/tests/client_transport.rs
+// AocClient over an in-memory Transport: scripted responses, recorded
+// requests, no sockets at all.
+
+use anyhow::Result;
+use aoc_lib::utils::{
+    AnswerHint, AocClient, HttpResponse, RetryPolicy, SubmitOutcome, Transport,
+};
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+use std::time::Duration;
+
+// One request the client made
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Sent {
+    method: &'static str,
+    url: String,
+    session: Option<String>,
+    form: Vec<(String, String)>,
+}
+
+// Answers requests in order from `replies`, recording each in `sent`.
+// Cloned handles share both, so a test keeps one after boxing the other.
+#[derive(Clone, Default)]
+struct FakeTransport {
+    replies: Rc<RefCell<VecDeque<Result<HttpResponse>>>>,
+    sent: Rc<RefCell<Vec<Sent>>>,
+}
+
+impl FakeTransport {
+    fn reply(&self, status: u16, body: &str) -> &Self {
+        self.replies.borrow_mut().push_back(Ok(HttpResponse {
+            status,
+            body: body.to_string(),
+        }));
+        self
+    }
+
+    fn fail(&self, message: &str) -> &Self {
+        self.replies
+            .borrow_mut()
+            .push_back(Err(anyhow::anyhow!(message.to_string())));
+        self
+    }
+
+    fn sent(&self) -> Vec<Sent> {
+        self.sent.borrow().clone()
+    }
+
+    fn answer(&self, sent: Sent) -> Result<HttpResponse> {
+        self.sent.borrow_mut().push(sent);
+        self.replies
+            .borrow_mut()
+            .pop_front()
+            .expect("unexpected request")
+    }
+}
+
+impl Transport for FakeTransport {
+    fn get(&self, url: &str, session: Option<&str>) -> Result<HttpResponse> {
+        self.answer(Sent {
+            method: "GET",
+            url: url.to_string(),
+            session: session.map(String::from),
+            form: Vec::new(),
+        })
+    }
+
+    fn post_form(
+        &self,
+        url: &str,
+        session: Option<&str>,
+        form: &[(&str, &str)],
+    ) -> Result<HttpResponse> {
+        self.answer(Sent {
+            method: "POST",
+            url: url.to_string(),
+            session: session.map(String::from),
+            form: form
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        })
+    }
+}
+
+// Retries without sleeping, so 5xx paths stay instant
+fn client(transport: &FakeTransport, session: Option<&str>) -> AocClient {
+    AocClient::new(
+        "https://aoc.test/",
+        session.map(String::from),
+        Box::new(transport.clone()),
+    )
+    .with_retry(RetryPolicy {
+        max_retries: 2,
+        base_delay: Duration::ZERO,
+    })
+}
+
+fn error_of<T: std::fmt::Debug>(result: Result<T>) -> String {
+    format!("{:#}", result.expect_err("request should fail"))
+}
+
+#[test]
+fn download_sends_the_session_and_returns_the_body() {
+    let transport = FakeTransport::default();
+    transport.reply(200, "1 2\n3 4\n");
+
+    let input = client(&transport, Some("session=abc"))
+        .download_input(2024, 3)
+        .unwrap();
+    assert_eq!(input, "1 2\n3 4\n");
+    assert_eq!(
+        transport.sent(),
+        [Sent {
+            method: "GET",
+            url: "https://aoc.test/2024/day/3/input".to_string(),
+            session: Some("abc".to_string()),
+            form: Vec::new(),
+        }]
+    );
+}
+
+#[test]
+fn download_needs_a_session_before_any_request() {
+    let transport = FakeTransport::default();
+    let err = error_of(client(&transport, None).download_input(2024, 3));
+    assert!(err.contains("No AoC session token found"), "{}", err);
+    assert!(transport.sent().is_empty());
+}
+
+#[test]
+fn submit_posts_the_trimmed_answer_and_parses_the_reply() {
+    let transport = FakeTransport::default();
+    transport
+        .reply(200, "<main><article><p>That's the right answer!</p></article></main>")
+        .reply(
+            200,
+            "<article><p>That's not the right answer; your answer is too low.</p></article>",
+        );
+    let client = client(&transport, Some("abc"));
+
+    assert_eq!(
+        client.submit_answer(2024, 3, 2, " 42\n").unwrap(),
+        SubmitOutcome::Correct
+    );
+    assert_eq!(
+        client.submit_answer(2024, 3, 1, "7").unwrap(),
+        SubmitOutcome::Wrong {
+            hint: Some(AnswerHint::TooLow),
+            wait: None,
+        }
+    );
+
+    let sent = transport.sent();
+    assert_eq!(sent[0].method, "POST");
+    assert_eq!(sent[0].url, "https://aoc.test/2024/day/3/answer");
+    assert_eq!(sent[0].session.as_deref(), Some("abc"));
+    assert_eq!(
+        sent[0].form,
+        [
+            ("level".to_string(), "2".to_string()),
+            ("answer".to_string(), "42".to_string()),
+        ]
+    );
+    assert_eq!(sent[1].form[0], ("level".to_string(), "1".to_string()));
+}
+
+#[test]
+fn submit_rejects_bad_parts_before_any_request() {
+    let transport = FakeTransport::default();
+    let err = error_of(
+        client(&transport, Some("abc")).submit_answer(2024, 3, 3, "1"),
+    );
+    assert!(err.contains("Part must be 1 or 2"), "{}", err);
+    assert!(transport.sent().is_empty());
+}
+
+#[test]
+fn http_errors_are_reported_with_their_status() {
+    let transport = FakeTransport::default();
+    transport
+        .reply(
+            404,
+            "Please don't repeatedly request this endpoint before it unlocks!",
+        )
+        .reply(400, "bad request")
+        .reply(200, "");
+    let client = client(&transport, Some("abc"));
+
+    let err = error_of(client.download_input(2024, 3));
+    assert!(
+        err.contains("Failed to download input: HTTP 404 (the puzzle is not unlocked yet)"),
+        "{}",
+        err
+    );
+    let err = error_of(client.submit_answer(2024, 3, 1, "1"));
+    assert!(err.contains("Failed to submit answer: HTTP 400"), "{}", err);
+    assert!(!err.contains("not unlocked"), "{}", err);
+    let err = error_of(client.download_input(2024, 3));
+    assert!(err.contains("it is empty"), "{}", err);
+}
+
+#[test]
+fn server_errors_are_retried_then_reported() {
+    let transport = FakeTransport::default();
+    transport.reply(502, "").reply(500, "").reply(200, "5\n");
+    let client = client(&transport, Some("abc"));
+    assert_eq!(client.download_input(2024, 3).unwrap(), "5\n");
+    assert_eq!(transport.sent().len(), 3);
+
+    // Two retries, then the last 5xx is the answer
+    transport.reply(503, "").reply(503, "").reply(503, "");
+    let err = error_of(client.download_input(2024, 3));
+    assert!(err.contains("HTTP 503"), "{}", err);
+    assert_eq!(transport.sent().len(), 6);
+}
+
+#[test]
+fn submissions_are_never_retried() {
+    let transport = FakeTransport::default();
+    transport.reply(502, "");
+    let err = error_of(
+        client(&transport, Some("abc")).submit_answer(2024, 3, 1, "1"),
+    );
+    assert!(err.contains("Failed to submit answer: HTTP 502"), "{}", err);
+    assert_eq!(transport.sent().len(), 1);
+}
+
+#[test]
+fn transport_failures_are_not_retried() {
+    let transport = FakeTransport::default();
+    transport.fail("connection refused");
+    let client = client(&transport, Some("abc"));
+
+    let err = error_of(client.download_input(2024, 3));
+    assert!(err.contains("connection refused"), "{}", err);
+    transport.fail("connection reset");
+    let err = error_of(client.submit_answer(2024, 3, 1, "1"));
+    assert!(
+        err.contains("Failed to send answer to AoC: connection reset"),
+        "{}",
+        err
+    );
+    assert_eq!(transport.sent().len(), 2);
+}
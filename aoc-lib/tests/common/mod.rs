@@ -0,0 +1,39 @@
+// aoc-lib/tests/common/mod.rs
+// Fixtures shared by the test binaries (aoc's tests include it by path);
+// each binary uses only some of them.
+#![allow(dead_code)]
+
+use std::path::{Path, PathBuf};
+
+/// A fresh temp directory for one test, removed on drop so it goes away
+/// even when an assertion fails
+pub struct ScratchDir {
+    path: PathBuf,
+}
+
+impl ScratchDir {
+    pub fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!(
+            "aoc-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path).unwrap();
+        Self { path }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn join(&self, path: impl AsRef<Path>) -> PathBuf {
+        self.path.join(path)
+    }
+}
+
+impl Drop for ScratchDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
@@ -0,0 +1,28 @@
+// aoc-lib/tests/config.rs
+// Settings from the config file. Its own test binary, since it clears
+// AOC_MIN_INTERVAL so the file's value is the one used.
+
+use aoc_lib::utils::config::{Config, DEFAULT_MIN_INTERVAL};
+use std::time::Duration;
+
+fn interval(secs: Option<f64>) -> anyhow::Result<Duration> {
+    Config {
+        min_interval: secs,
+        ..Config::default()
+    }
+    .min_interval()
+}
+
+#[test]
+fn min_interval_must_be_a_representable_duration() {
+    std::env::remove_var("AOC_MIN_INTERVAL");
+
+    assert_eq!(interval(None).unwrap(), DEFAULT_MIN_INTERVAL);
+    assert_eq!(interval(Some(0.0)).unwrap(), Duration::ZERO);
+    assert_eq!(interval(Some(1.5)).unwrap(), Duration::from_millis(1500));
+
+    for secs in [1e300, f64::INFINITY, f64::NAN, -1.0] {
+        let err = interval(Some(secs)).unwrap_err().to_string();
+        assert!(err.contains("Invalid minimum request interval"), "{}", err);
+    }
+}
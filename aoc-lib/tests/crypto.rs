@@ -0,0 +1,106 @@
+// aoc-lib/tests/crypto.rs
+// Encrypted-at-rest inputs: round trips, wrong keys and tree conversion.
+
+mod common;
+
+use aoc_lib::utils::crypto::{
+    decrypt_file, encrypt_file, encrypted_path, find_inputs, InputKey,
+};
+use aoc_lib::utils::paths::DEFAULT_INPUT_LAYOUT;
+use common::ScratchDir;
+
+fn key(secret: &str) -> InputKey {
+    InputKey::from_secret(secret).unwrap()
+}
+
+#[test]
+fn encrypt_round_trips_with_fresh_nonces() {
+    let key = key("correct horse battery staple");
+    let first = key.encrypt(b"1 2 3\n").unwrap();
+    let second = key.encrypt(b"1 2 3\n").unwrap();
+
+    assert_ne!(first, second);
+    assert_eq!(key.decrypt(&first).unwrap(), b"1 2 3\n");
+    assert_eq!(key.decrypt(&second).unwrap(), b"1 2 3\n");
+}
+
+#[test]
+fn each_key_salts_its_files() {
+    let first = key("same secret").encrypt(b"input").unwrap();
+    let second = key("same secret").encrypt(b"input").unwrap();
+
+    // Header: magic, 16-byte salt, nonce; another load gets another salt
+    assert!(first.starts_with(b"AOCENC2\n"));
+    assert_ne!(first[8..24], second[8..24]);
+    let reader = key("same secret");
+    assert_eq!(reader.decrypt(&first).unwrap(), b"input");
+    assert_eq!(reader.decrypt(&second).unwrap(), b"input");
+}
+
+#[test]
+fn wrong_key_or_tampering_is_rejected() {
+    let data = key("right").encrypt(b"secret input").unwrap();
+    assert!(key("wrong").decrypt(&data).is_err());
+
+    let mut tampered = data.clone();
+    *tampered.last_mut().unwrap() ^= 1;
+    assert!(key("right").decrypt(&tampered).is_err());
+    assert!(key("right").decrypt(b"plain text").is_err());
+    assert!(InputKey::from_secret("  \n").is_err());
+}
+
+#[test]
+fn input_tree_converts_both_ways() {
+    let dir = ScratchDir::new("crypto-tree");
+    let root = dir.path();
+    std::fs::create_dir_all(root.join("year2024")).unwrap();
+    std::fs::write(root.join("year2024/day01.txt"), "1\n").unwrap();
+    std::fs::write(root.join("year2024/day01.example1.txt"), "ex\n").unwrap();
+    let key = key("tree");
+
+    let plain = find_inputs(root, DEFAULT_INPUT_LAYOUT, false).unwrap();
+    assert_eq!(plain, [root.join("year2024/day01.txt")]);
+
+    let encrypted = encrypt_file(&key, &plain[0]).unwrap();
+    assert_eq!(encrypted, encrypted_path(&plain[0]));
+    assert_eq!(
+        find_inputs(root, DEFAULT_INPUT_LAYOUT, true).unwrap(),
+        vec![encrypted.clone()]
+    );
+
+    std::fs::remove_file(&plain[0]).unwrap();
+    let decrypted = decrypt_file(&key, &encrypted).unwrap();
+    assert_eq!(decrypted, plain[0]);
+    assert_eq!(std::fs::read_to_string(&decrypted).unwrap(), "1\n");
+}
+
+#[test]
+fn only_files_named_by_the_layout_are_inputs() {
+    let dir = ScratchDir::new("crypto-layout");
+    let root = dir.path();
+    for file in [
+        "year2024/day01.txt",
+        "work/year2024/day02.txt",
+        "notes.txt",
+        "year2024/notes.txt",
+        "year2024/day1.txt",
+        "year2024/day01.example1.txt",
+        "year2024/old/day03.txt",
+    ] {
+        let path = root.join(file);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, "x\n").unwrap();
+    }
+
+    assert_eq!(
+        find_inputs(root, DEFAULT_INPUT_LAYOUT, false).unwrap(),
+        vec![
+            root.join("work/year2024/day02.txt"),
+            root.join("year2024/day01.txt")
+        ]
+    );
+    assert_eq!(
+        find_inputs(root, "{profile}/{year}-{day}.txt", false).unwrap(),
+        Vec::<std::path::PathBuf>::new()
+    );
+}
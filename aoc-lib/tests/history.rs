@@ -0,0 +1,113 @@
+// aoc-lib/tests/history.rs
+// Submission history: the rules that refuse a submission before it is
+// posted, and the TOML file they are learned from.
+
+mod common;
+
+use aoc_lib::utils::history::{AttemptOutcome, SubmissionHistory};
+use common::ScratchDir;
+
+#[test]
+fn repeated_wrong_answers_are_refused() {
+    let dir = ScratchDir::new("history-wrong");
+    let path = dir.join("year2024.history.toml");
+    let mut history = SubmissionHistory::load_from(2024, &path).unwrap();
+    assert_eq!(history.refusal(1, 1, "abc"), None);
+
+    history.record(1, 1, "abc", AttemptOutcome::Wrong).unwrap();
+    assert_eq!(
+        history.refusal(1, 1, " abc\n").as_deref(),
+        Some("abc was already rejected (wrong)")
+    );
+    // Other answers, parts and days are still allowed
+    assert_eq!(history.refusal(1, 1, "abd"), None);
+    assert_eq!(history.refusal(1, 2, "abc"), None);
+    assert_eq!(history.refusal(2, 1, "abc"), None);
+
+    // Rate limiting says nothing about the answer itself
+    history
+        .record(1, 1, "xyz", AttemptOutcome::RateLimited)
+        .unwrap();
+    assert_eq!(history.refusal(1, 1, "xyz"), None);
+}
+
+#[test]
+fn answers_outside_the_learned_bounds_are_refused() {
+    let dir = ScratchDir::new("history-bounds");
+    let path = dir.join("year2024.history.toml");
+    let mut history = SubmissionHistory::load_from(2024, &path).unwrap();
+    history
+        .record(1, 1, "100", AttemptOutcome::TooHigh)
+        .unwrap();
+    history.record(1, 1, "80", AttemptOutcome::TooHigh).unwrap();
+    history.record(1, 1, "10", AttemptOutcome::TooLow).unwrap();
+    history.record(1, 1, "20", AttemptOutcome::TooLow).unwrap();
+
+    // The tightest bound wins, and the bound itself is excluded
+    assert_eq!(
+        history.refusal(1, 1, "90").as_deref(),
+        Some("80 was too high, so 90 is too")
+    );
+    assert_eq!(
+        history.refusal(1, 1, "20").as_deref(),
+        Some("20 was already rejected (too low)")
+    );
+    assert_eq!(
+        history.refusal(1, 1, "15").as_deref(),
+        Some("20 was too low, so 15 is too")
+    );
+    assert_eq!(history.refusal(1, 1, "21"), None);
+    assert_eq!(history.refusal(1, 1, "79"), None);
+    // Bounds only apply to numbers
+    assert_eq!(history.refusal(1, 1, "ABC"), None);
+}
+
+#[test]
+fn a_solved_part_refuses_further_answers() {
+    let dir = ScratchDir::new("history-solved");
+    let path = dir.join("year2024.history.toml");
+    let mut history = SubmissionHistory::load_from(2024, &path).unwrap();
+    history.record(1, 1, "42", AttemptOutcome::Correct).unwrap();
+
+    assert_eq!(
+        history.refusal(1, 1, "43").as_deref(),
+        Some("part 1 was already solved with 42")
+    );
+    assert_eq!(
+        history.refusal(1, 1, "42").as_deref(),
+        Some("part 1 was already solved with 42")
+    );
+    assert!(history.is_solved(1, 1));
+    assert!(!history.is_solved(1, 2));
+    assert_eq!(history.refusal(1, 2, "43"), None);
+}
+
+#[test]
+fn history_round_trips_through_its_file() {
+    let dir = ScratchDir::new("history-round-trip");
+    let path = dir.join("year2024.history.toml");
+    let mut history = SubmissionHistory::load_from(2024, &path).unwrap();
+    history.record(3, 1, "7", AttemptOutcome::TooLow).unwrap();
+    history.record(3, 1, "9", AttemptOutcome::Correct).unwrap();
+    history
+        .record(3, 2, "x y", AttemptOutcome::Unknown)
+        .unwrap();
+
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert!(content.contains("[[attempt]]"), "{}", content);
+    assert!(content.contains("outcome = \"too_low\""), "{}", content);
+
+    let loaded = SubmissionHistory::load_from(2024, &path).unwrap();
+    assert_eq!(loaded.year(), 2024);
+    assert_eq!(loaded.attempts(3, None), history.attempts(3, None));
+    assert_eq!(loaded.attempts(3, Some(2))[0].answer, "x y");
+    assert!(loaded.is_solved(3, 1));
+
+    std::fs::write(&path, "attempt = 5").unwrap();
+    let err = SubmissionHistory::load_from(2024, &path).err().unwrap();
+    assert!(
+        format!("{:#}", err).contains("Failed to parse"),
+        "{:#}",
+        err
+    );
+}
@@ -0,0 +1,82 @@
+// aoc-lib/tests/input_store.rs
+// Cached inputs on disk, plain and encrypted, against the mock server.
+// Its own test binary, since it sets AOC_INPUT_DIR and AOC_INPUT_KEY.
+
+mod common;
+
+use aoc_lib::utils::{
+    ensure_input_with, get_input_path, load_input, AocClient, ReqwestTransport,
+};
+use common::ScratchDir;
+use mock_aoc::{MockAoc, MockConfig};
+use std::sync::{Mutex, MutexGuard};
+
+// Tests here share the process environment, so they take turns
+static ENV: Mutex<()> = Mutex::new(());
+
+// A fresh input root of the test's own, set as AOC_INPUT_DIR with an input
+// key configured; hold the guard for the whole test
+fn input_dir(name: &str) -> (MutexGuard<'static, ()>, ScratchDir) {
+    let guard = ENV.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let dir = ScratchDir::new(&format!("input-store-{}", name));
+    std::env::set_var("AOC_INPUT_DIR", dir.path());
+    std::env::set_var("AOC_INPUT_KEY", "secret");
+    std::env::remove_var("AOC_PROFILE");
+    (guard, dir)
+}
+
+#[test]
+fn encrypting_a_download_removes_the_stale_plain_file() {
+    let (_env, dir) = input_dir("encrypt");
+
+    // An expired session's login page, saved before the key was set
+    let path = get_input_path(2024, 1).unwrap();
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(&path, "<!DOCTYPE html>\n<html>Log in</html>\n").unwrap();
+
+    let server = MockAoc::start(MockConfig::default()).unwrap();
+    let client = AocClient::new(
+        server.base_url(),
+        Some("test-session".to_string()),
+        Box::new(ReqwestTransport::new().unwrap()),
+    );
+    assert_eq!(
+        ensure_input_with(&client, 2024, 1).unwrap(),
+        "3   4\n4   3\n"
+    );
+    assert!(!path.exists());
+    assert!(dir.join("year2024/day01.txt.enc").exists());
+
+    // The encrypted copy is now the cache: no second download
+    assert_eq!(load_input(2024, 1).unwrap(), "3   4\n4   3\n");
+    assert_eq!(
+        ensure_input_with(&client, 2024, 1).unwrap(),
+        "3   4\n4   3\n"
+    );
+    assert_eq!(server.requests().len(), 1);
+
+    // A bad plain file left beside an encrypted copy does not shadow it
+    std::fs::write(&path, "").unwrap();
+    assert_eq!(load_input(2024, 1).unwrap(), "3   4\n4   3\n");
+    assert_eq!(
+        ensure_input_with(&client, 2024, 1).unwrap(),
+        "3   4\n4   3\n"
+    );
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn unusable_cached_input_is_refused() {
+    let (_env, _dir) = input_dir("unusable");
+    let path = get_input_path(2024, 2).unwrap();
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+
+    for content in ["", "\n<html><body>Log in</body></html>\n"] {
+        std::fs::write(&path, content).unwrap();
+        let err = load_input(2024, 2).unwrap_err().to_string();
+        assert!(err.contains("Input file is unusable"), "{}", err);
+        assert!(err.contains("aoc download 2024 2"), "{}", err);
+    }
+    std::fs::write(&path, "<<>>\n").unwrap();
+    assert_eq!(load_input(2024, 2).unwrap(), "<<>>\n");
+}
@@ -0,0 +1,61 @@
+// aoc-lib/tests/paths.rs
+// Path resolution; cargo runs these from aoc-lib/, not the workspace root.
+
+use aoc_lib::utils::paths::{
+    layout_matches, render_layout, validate_layout, workspace_root,
+};
+use std::path::PathBuf;
+
+#[test]
+fn workspace_root_is_found_from_crate_directory() {
+    let root = workspace_root();
+    assert!(root.join("aoc-lib").is_dir(), "{}", root.display());
+    assert!(root.join("Cargo.toml").is_file(), "{}", root.display());
+}
+
+#[test]
+fn layouts_need_year_and_day() {
+    assert!(validate_layout("year{year}/day{day}.txt").is_ok());
+    assert!(validate_layout("{profile}/{year}/{day}/input.txt").is_ok());
+    assert!(validate_layout("{year}/input.txt").is_err());
+    assert!(validate_layout("/inputs/{year}/{day}.txt").is_err());
+
+    let err = validate_layout("{year}/{day}/{user}.txt").unwrap_err();
+    assert!(err.to_string().contains("{user}"), "{}", err);
+}
+
+#[test]
+fn layouts_render_padded_days_and_profile() {
+    assert_eq!(
+        render_layout("year{year}/day{day}.txt", 2024, 5, None),
+        PathBuf::from("year2024/day05.txt")
+    );
+    assert_eq!(
+        render_layout(
+            "{profile}/{year}/{day}/input.txt",
+            2019,
+            12,
+            Some("google")
+        ),
+        PathBuf::from("google/2019/12/input.txt")
+    );
+    assert_eq!(
+        render_layout("{profile}/{year}-{day}.txt", 2019, 1, None),
+        PathBuf::from("default/2019-01.txt")
+    );
+}
+
+#[test]
+fn layouts_match_only_the_names_they_render() {
+    let layout = "year{year}/day{day}.txt";
+    assert!(layout_matches(layout, "year2024/day05.txt"));
+    assert!(!layout_matches(layout, "year2024/day5.txt"));
+    assert!(!layout_matches(layout, "year2024/day05.example1.txt"));
+    assert!(!layout_matches(layout, "year2024/notes.txt"));
+    assert!(!layout_matches(layout, "notes/year2024/day05.txt"));
+
+    let layout = "{profile}/{year}-{day}.txt";
+    assert!(layout_matches(layout, "work-2/2024-05.txt"));
+    assert!(!layout_matches(layout, "/2024-05.txt"));
+    assert!(!layout_matches(layout, "a/b/2024-05.txt"));
+}
@@ -0,0 +1,27 @@
+// aoc-lib/tests/profile.rs
+// AOC_PROFILE becomes part of data paths, so it is validated like
+// --profile. Its own test binary, since it sets process environment.
+
+use aoc_lib::utils::answers::get_answers_path;
+use aoc_lib::utils::get_input_path;
+use aoc_lib::utils::session::active_profile;
+
+#[test]
+fn profile_from_the_environment_is_validated() {
+    std::env::set_var("AOC_PROFILE", "work_2");
+    assert_eq!(active_profile().unwrap().as_deref(), Some("work_2"));
+    assert!(get_input_path(2024, 1)
+        .unwrap()
+        .ends_with("work_2/year2024/day01.txt"));
+
+    std::env::set_var("AOC_PROFILE", "default");
+    assert_eq!(active_profile().unwrap(), None);
+
+    std::env::set_var("AOC_PROFILE", "../../x");
+    let err = active_profile().unwrap_err().to_string();
+    assert!(err.contains("Invalid profile name \"../../x\""), "{}", err);
+    assert!(get_input_path(2024, 1).is_err());
+    assert!(get_answers_path(2024).is_err());
+
+    std::env::remove_var("AOC_PROFILE");
+}
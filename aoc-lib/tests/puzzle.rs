@@ -0,0 +1,240 @@
+// aoc-lib/tests/puzzle.rs
+// Puzzle pages: caching through the mock server, rendering to terminal text
+// and guessing the examples.
+
+mod common;
+
+use aoc_lib::utils::puzzle::{
+    cache_puzzle_with, decode_entities, extract_examples, has_part_two,
+    puzzle_articles, puzzle_title, render_puzzle, ExampleAnswer,
+};
+use aoc_lib::utils::{AocClient, ReqwestTransport, SubmitOutcome};
+use common::ScratchDir;
+use mock_aoc::{MockAoc, MockConfig};
+
+const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 5: Print Queue ---</h2>
+<p>Satisfied with their search on Ceres, the squadron of scholars
+suggests <a href="/2024/day/4">subsequently</a> scanning the stationery stacks.</p>
+<pre><code>47|53
+97|13
+
+<em>75,47,61,53,29</em>
+</code></pre>
+<p>The first rule, <code>47|53</code>, means that <em>if</em> an update includes
+both page 47 and page 53, then page 47 &lt;must&gt; be printed first.</p>
+<ul>
+<li>Page <code>75</code> is correctly first.</li>
+<li>Page <code>47</code> is second &amp; last.</li>
+</ul>
+</article>
+<p>Your puzzle answer was <code>143</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>For each of the <em>incorrectly-ordered updates</em>, use the rules.</p>
+</article>
+</main></body></html>"#;
+
+fn plain(html: &str) -> String {
+    colored::control::set_override(false);
+    render_puzzle(html)
+}
+
+#[test]
+fn articles_and_answers_are_extracted_in_order() {
+    let articles = puzzle_articles(PAGE);
+    assert_eq!(articles.len(), 3);
+    assert!(articles[0].contains("Print Queue"));
+    assert_eq!(
+        articles[1],
+        "<p>Your puzzle answer was <code>143</code>.</p>"
+    );
+    assert!(articles[2].contains("Part Two"));
+    assert!(has_part_two(PAGE));
+}
+
+#[test]
+fn renders_headings_paragraphs_and_lists() {
+    let text = plain(PAGE);
+    assert!(
+        text.starts_with("--- Day 5: Print Queue ---\n\nSatisfied with"),
+        "{}",
+        text
+    );
+    // Paragraph line breaks are reflowed, links keep their text
+    assert!(
+        text.contains("of scholars suggests\nsubsequently scanning the"),
+        "{}",
+        text
+    );
+    assert!(
+        text.contains("then page 47 <must> be printed first."),
+        "{}",
+        text
+    );
+    assert!(text.contains(
+        "\n  - Page 75 is correctly first.\n  - Page 47 is second & last.\n"
+    ));
+    assert!(
+        text.contains("Your puzzle answer was 143.\n\n--- Part Two ---"),
+        "{}",
+        text
+    );
+    assert!(text.ends_with("use the rules.\n"), "{}", text);
+}
+
+#[test]
+fn code_blocks_keep_their_lines_indented() {
+    let text = plain(PAGE);
+    assert!(
+        text.contains("\n\n    47|53\n    97|13\n\n    75,47,61,53,29\n\n"),
+        "{}",
+        text
+    );
+}
+
+#[test]
+fn paragraphs_wrap_at_80_columns() {
+    let html = format!("<article><p>{}</p></article>", "word ".repeat(40));
+    let text = plain(&html);
+    assert!(text.lines().all(|line| line.len() <= 80), "{}", text);
+    assert_eq!(text.lines().count(), 3);
+}
+
+#[test]
+fn title_comes_from_the_day_heading() {
+    assert_eq!(puzzle_title(PAGE).as_deref(), Some("Print Queue"));
+    assert_eq!(
+        puzzle_title("<h2>--- Day 3: Mull It Over &amp; Out ---</h2>")
+            .as_deref(),
+        Some("Mull It Over & Out")
+    );
+    assert_eq!(puzzle_title("<h2>--- Part Two ---</h2>"), None);
+}
+
+#[test]
+fn entities_are_decoded() {
+    assert_eq!(
+        decode_entities("a &lt;b&gt; &amp; &quot;c&quot;"),
+        "a <b> & \"c\""
+    );
+    assert_eq!(decode_entities("&#39;x&#x27;"), "'x'");
+    assert_eq!(decode_entities("AT&T &unknown; &"), "AT&T &unknown; &");
+}
+
+#[test]
+fn cached_page_gains_part_two_after_solving() {
+    let server =
+        MockAoc::start(MockConfig::default()).expect("start mock server");
+    let client = AocClient::new(
+        server.base_url(),
+        Some("test-session".to_string()),
+        Box::new(ReqwestTransport::new().expect("build transport")),
+    );
+    let dir = ScratchDir::new("puzzle");
+    let path = dir.join("year2024/day01.html");
+
+    let html = cache_puzzle_with(&client, &path, 2024, 1).unwrap();
+    assert!(!has_part_two(&html));
+    let examples = extract_examples(&html);
+    assert_eq!(examples.inputs.len(), 1);
+    assert_eq!(examples.answers[0].as_ref().unwrap().answer, "11");
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), html);
+
+    let outcome = client.submit_answer(2024, 1, 1, "11").unwrap();
+    assert_eq!(outcome, SubmitOutcome::Correct);
+    let html = cache_puzzle_with(&client, &path, 2024, 1).unwrap();
+    assert!(has_part_two(&html));
+    assert!(plain(&html).contains("Your puzzle answer was 11."));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), html);
+}
+
+const EXAMPLES_PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2>
+<p>The newly-improved calibration document consists of lines of text.</p>
+<p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+</code></pre>
+<p>In this example, the calibration values are <code>12</code> and <code>38</code>.
+Adding these together produces <code><em>50</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>54667</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Some digits are spelled out. For example:</p>
+<pre><code>two1nine
+<em>eight</em>wothree
+</code></pre>
+<p>In this example, the values are <code>29</code> and <code>83</code>.
+Adding these produces <code><em>112</em></code>.</p>
+</article>
+</main>"#;
+
+#[test]
+fn examples_and_answers_are_extracted_per_part() {
+    let examples = extract_examples(EXAMPLES_PAGE);
+    assert_eq!(
+        examples.inputs,
+        vec![
+            "1abc2\npqr3stu8vwx\n".to_string(),
+            "two1nine\neightwothree\n".to_string()
+        ]
+    );
+    assert_eq!(
+        examples.answers,
+        [
+            Some(ExampleAnswer {
+                answer: "50".to_string(),
+                example: 0
+            }),
+            Some(ExampleAnswer {
+                answer: "112".to_string(),
+                example: 1
+            }),
+        ]
+    );
+}
+
+#[test]
+fn part_two_reuses_the_first_example_and_intermediate_blocks_are_skipped() {
+    let html = r#"<article><h2>--- Day 9 ---</h2>
+<p>For example, suppose you have the following report:</p>
+<pre><code>0 3 6
+1 3 6
+</code></pre>
+<p>After one step, the grid looks like this:</p>
+<pre><code>..#
+#..
+</code></pre>
+<p>The total is <code><em>114</em></code>.</p>
+</article>
+<article><h2>--- Part Two ---</h2>
+<p>Doing this for the example above gives <em><code>2</code></em>.</p>
+</article>"#;
+    let examples = extract_examples(html);
+    assert_eq!(examples.inputs, vec!["0 3 6\n1 3 6\n".to_string()]);
+    assert_eq!(examples.answers[0].as_ref().unwrap().answer, "114");
+    assert_eq!(
+        examples.answers[1],
+        Some(ExampleAnswer {
+            answer: "2".to_string(),
+            example: 0
+        })
+    );
+}
+
+#[test]
+fn first_block_is_the_example_when_none_is_introduced() {
+    let html = "<article><p>Consider this:</p>\
+        <pre><code>a &lt; b\n</code></pre>\
+        <p>That gives <code><em>7</em></code>.</p></article>";
+    let examples = extract_examples(html);
+    assert_eq!(examples.inputs, vec!["a < b\n".to_string()]);
+    assert_eq!(examples.answers[0].as_ref().unwrap().answer, "7");
+    assert_eq!(examples.answers[1], None);
+
+    let none = extract_examples(
+        "<article><p>No code here, <code><em>5</em></code></p></article>",
+    );
+    assert!(none.inputs.is_empty());
+    assert_eq!(none.answers, [None, None]);
+}
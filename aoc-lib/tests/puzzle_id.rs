@@ -0,0 +1,100 @@
+// aoc-lib/tests/puzzle_id.rs
+// Parsing and validating puzzle ids; "today" is checked at fixed times.
+
+use aoc_lib::utils::calendar::unlock_time;
+use aoc_lib::PuzzleId;
+
+fn id(year: u16, day: u8) -> PuzzleId {
+    PuzzleId::new(year, day).unwrap()
+}
+
+#[test]
+fn accepts_every_documented_form() {
+    for input in [
+        "2024 5",
+        "2024/5",
+        "2024-05",
+        "24d5",
+        " 2024  05 ",
+        "2024D5",
+    ] {
+        let parsed: PuzzleId = input.parse().unwrap();
+        assert_eq!(parsed, id(2024, 5), "{:?}", input);
+    }
+}
+
+#[test]
+fn displays_canonically_and_parses_back() {
+    let puzzle = PuzzleId::new(2024, 5).unwrap();
+    assert_eq!((puzzle.year(), puzzle.day()), (2024, 5));
+    assert_eq!(puzzle.to_string(), "year2024/day05");
+    assert_eq!("year2024/day05".parse::<PuzzleId>().unwrap(), puzzle);
+}
+
+#[test]
+fn rejects_malformed_and_out_of_range_ids() {
+    for input in ["", "2024", "2024/", "/5", "2024/x", "2024.5", "2024/5/1"] {
+        let err = input.parse::<PuzzleId>().unwrap_err().to_string();
+        assert!(err.contains("Invalid puzzle"), "{:?}: {}", input, err);
+    }
+
+    let err = "2014/1".parse::<PuzzleId>().unwrap_err().to_string();
+    assert!(err.contains("between 2015 and 2099"), "{}", err);
+    let err = "2030/13".parse::<PuzzleId>().unwrap_err().to_string();
+    assert!(err.contains("between 1 and 12"), "{}", err);
+    assert!("2015/0".parse::<PuzzleId>().is_err());
+    assert!("2015/25".parse::<PuzzleId>().is_ok());
+}
+
+#[test]
+fn today_is_the_latest_unlocked_puzzle() {
+    let unlock = unlock_time(2024, 5);
+    assert_eq!(PuzzleId::live_at(unlock).unwrap(), id(2024, 5));
+    assert_eq!(PuzzleId::live_at(unlock - 1).unwrap(), id(2024, 4));
+    assert_eq!(
+        PuzzleId::live_at(unlock_time(2025, 12)).unwrap(),
+        id(2025, 12)
+    );
+
+    let err = PuzzleId::live_at(unlock_time(2024, 1) - 1)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("(2024-11-30 EST)"), "{}", err);
+    assert!(
+        err.contains("The 2024 event starts at 2024-12-01 05:00:00 UTC (midnight EST), in 1s"),
+        "{}",
+        err
+    );
+    let err = PuzzleId::live_at(unlock_time(2025, 13))
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("the 2025 event ended on December 12"),
+        "{}",
+        err
+    );
+    assert!(
+        err.contains("The 2026 event starts at 2026-12-01"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn args_give_the_puzzle_and_what_follows() {
+    let args =
+        |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+    let submit = args(&["2024", "5", "1", "42"]);
+    let (puzzle, rest) = PuzzleId::from_args(&submit).unwrap();
+    assert_eq!(puzzle, id(2024, 5));
+    assert_eq!(rest, &submit[2..]);
+
+    let submit = args(&["2024/5", "1"]);
+    let (puzzle, rest) = PuzzleId::from_args(&submit).unwrap();
+    assert_eq!(puzzle, id(2024, 5));
+    assert_eq!(rest, &submit[1..]);
+
+    assert!(PuzzleId::from_args(&[]).is_err());
+    assert!(PuzzleId::from_args(&args(&["2024"])).is_err());
+}
@@ -0,0 +1,24 @@
+// AUTO-GENERATED - DO NOT EDIT MANUALLY
+// Regenerate with: cargo run --bin registry-tool
+//
+// One test per registered day, checked against answers/yearYYYY.toml.
+// Days without an input file or recorded answers are skipped.
+
+use aoc_lib::PuzzleId;
+
+fn check(puzzle: &str) {
+    // Also fails if a registered day is past the year's DAY_COUNT
+    let id: PuzzleId = puzzle.parse().unwrap();
+    // Inputs and answers resolve from the workspace root, whatever the cwd
+    aoc_lib::utils::answers::assert_recorded_answers(id.year(), id.day());
+}
+
+#[test]
+fn year2024_day01() {
+    check("year2024/day01");
+}
+
+#[test]
+fn year2025_day01() {
+    check("year2025/day01");
+}
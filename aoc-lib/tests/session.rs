@@ -0,0 +1,34 @@
+// aoc-lib/tests/session.rs
+// Saved session tokens. Its own test binary, since it sets AOC_CONFIG_DIR.
+
+mod common;
+
+use aoc_lib::utils::session::save_session;
+use common::ScratchDir;
+
+#[cfg(unix)]
+#[test]
+fn saved_sessions_are_owner_only() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = ScratchDir::new("session");
+    std::env::set_var("AOC_CONFIG_DIR", dir.path());
+    let mode = |path: &std::path::Path| {
+        std::fs::metadata(path).unwrap().permissions().mode() & 0o777
+    };
+
+    let path = save_session(None, "session=abc123\n").unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "abc123\n");
+    assert_eq!(mode(&path), 0o600);
+
+    // A file left readable by an older version is restricted on save
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644))
+        .unwrap();
+    let path = save_session(None, "def").unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "def\n");
+    assert_eq!(mode(&path), 0o600);
+
+    let path = save_session(Some("work"), "ghi").unwrap();
+    assert!(path.ends_with("profiles/work"));
+    assert_eq!(mode(&path), 0o600);
+}
@@ -0,0 +1,46 @@
+// aoc-lib/tests/testing.rs
+// The example-test helpers days use in their own `#[cfg(test)]` modules.
+
+use anyhow::Result;
+use aoc_lib::aoc_example_test;
+use aoc_lib::utils::testing::assert_example;
+
+const EXAMPLE: &str = "1\n2\n3\n";
+
+fn part1(input: &str) -> Result<String> {
+    let sum: u32 = input.lines().map(|line| line.parse::<u32>().unwrap()).sum();
+    Ok(sum.to_string())
+}
+
+fn part2(input: &str) -> Result<String> {
+    Ok(input.lines().rev().collect::<Vec<_>>().join("\n"))
+}
+
+// Expands to `part1::example` and `part2::example`
+aoc_example_test!(part1, EXAMPLE, 6);
+aoc_example_test!(part2, EXAMPLE, "3\n2\n1\n");
+// Further examples of a part are named tests beside those
+aoc_example_test!(part1, single_line, "40\n", 40);
+aoc_example_test!(part1, no_lines, "", "0");
+
+#[test]
+#[should_panic(
+    expected = "part1 answer does not match the example\n  expected: 7\n    actual: 6\n"
+)]
+fn a_wrong_answer_shows_expected_and_actual() {
+    assert_example("part1", part1(EXAMPLE), 7);
+}
+
+#[test]
+#[should_panic(
+    expected = "part2 answer does not match the example\n- 1\n+ 3\n  2\n- 3\n+ 1\n"
+)]
+fn a_wrong_multi_line_answer_is_diffed_line_by_line() {
+    assert_example("part2", part2(EXAMPLE), "1\n2\n3");
+}
+
+#[test]
+#[should_panic(expected = "part1 failed on the example: bad input")]
+fn an_error_names_the_part() {
+    assert_example("part1", Err(anyhow::anyhow!("bad input")), 6);
+}
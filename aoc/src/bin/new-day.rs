@@ -4,36 +4,39 @@
 // - Generate a new day solution stub at `aoc-lib/src/yearYYYY/dayDD.rs`
 // - Register the day in `aoc-lib/src/yearYYYY/mod.rs` by:
 //     * adding `mod dayDD;` once,
+//     * adding `("D", dayDD::TITLE, dayDD::part1, dayDD::part2)` to DAYS
+// - Store the puzzle title (from the puzzle page or `--title`) as `TITLE`
 
 use anyhow::{anyhow, Context, Result};
+use aoc_lib::utils::calendar::{day_count, is_last_day};
+use aoc_lib::utils::{
+    extract_examples, get_example_path, get_input_path, load_puzzle,
+    puzzle_title, PuzzleExamples,
+};
+use aoc_lib::PuzzleId;
 use std::fs;
 use std::path::PathBuf;
 
 fn main() -> Result<()> {
-    // Expect: cargo run --bin new-day <year> <day>
+    // Expect: cargo run --bin new-day <puzzle> [--title "Puzzle Title"],
+    // where the puzzle is `<year> <day>`, `2024/5`, `24d5`, `today`, ...
     let mut args = std::env::args().skip(1).collect::<Vec<_>>();
-    if args.len() != 2 {
-        anyhow::bail!("Usage: cargo run --bin new-day <year> <day>");
+    let title_arg = take_option(&mut args, "--title")?;
+    let usage = "Usage: cargo run --bin new-day <year> <day> [--title \"Puzzle Title\"]";
+    if args.is_empty() {
+        anyhow::bail!(usage);
     }
-    let year: u16 = args.remove(0).parse().context("invalid year")?;
-    let day: u8 = args.remove(0).parse().context("invalid day")?;
 
-    // Validate year range
-    if !(2015..=2099).contains(&year) {
-        anyhow::bail!("Year must be between 2015 and 2099 (Advent of Code years)");
-    }
-
-    // Sanity check day range
-    // ToDo: hardcoded: Starting 2025, AoC is now only 12 days
-    //       intentionally leaving range of 1 to 25 so we can still use code for previous years
-    //     **  In future, I'll figure out a way to create a dynamic variable
-    //      that is set as part of the Day 1 template creation
-    if !(1..=25).contains(&day) {
-        anyhow::bail!("Day must be between 1 and 25");
+    // Checks the year range and the day against the year's DAY_COUNT, or
+    // the schedule's default for a new year (25 days until 2024, 12 from 2025)
+    let (id, rest) = PuzzleId::from_args(&args)?;
+    if !rest.is_empty() {
+        anyhow::bail!(usage);
     }
+    let (year, day) = (id.year(), id.day());
 
     // Create per-year day file path and parent directories
-    let solution_path = solution_rs_path(year, day);
+    let solution_path = solution_rs_path(id);
     if solution_path.exists() {
         println!(
             "Solution file already exists for year {} day {}: {}",
@@ -45,18 +48,67 @@ fn main() -> Result<()> {
     }
 
     if let Some(parent) = solution_path.parent() {
-        fs::create_dir_all(parent)
-            .with_context(|| format!("failed to create directory {}", parent.display()))?;
+        fs::create_dir_all(parent).with_context(|| {
+            format!("failed to create directory {}", parent.display())
+        })?;
     }
 
-    // Write a minimal day stub
-    fs::write(&solution_path, build_day_stub(year, day))
-        .with_context(|| format!("failed to write {}", solution_path.display()))?;
+    // Pull the title, examples and their answers from the puzzle page, if we can
+    let page = fetch_puzzle_page(year, day);
+    let examples = page.as_deref().map(extract_examples).unwrap_or_default();
+    if page.is_some() {
+        if examples.inputs.is_empty() {
+            println!(
+                "No example found on the puzzle page; paste it in by hand"
+            );
+        } else {
+            println!(
+                "Found {} example(s); check them against the puzzle",
+                examples.inputs.len()
+            );
+        }
+    }
+    let title = title_arg
+        .or_else(|| page.as_deref().and_then(puzzle_title))
+        .unwrap_or_default();
+    if title.is_empty() {
+        println!("No puzzle title; set TITLE in the day file or pass --title");
+    } else {
+        println!("Title: {}", title);
+    }
 
-    // Create input directory (but not the day file - let download command handle that)
-    let input_dir = PathBuf::from(format!("input/year{}", year));
-    fs::create_dir_all(&input_dir)
-        .with_context(|| format!("failed to create input directory {}", input_dir.display()))?;
+    // Write a day stub, with the example test filled in when found
+    let part2_test = !is_last_day(year, day);
+    fs::write(
+        &solution_path,
+        build_day_stub(&title, &examples, part2_test),
+    )
+    .with_context(|| format!("failed to write {}", solution_path.display()))?;
+
+    // Write the examples to the input root, or an empty example file to
+    // paste into (but not the input itself - let download command handle that)
+    let example_path = get_example_path(year, day, 1);
+    if let Some(input_dir) = example_path.parent() {
+        fs::create_dir_all(input_dir).with_context(|| {
+            format!("failed to create input directory {}", input_dir.display())
+        })?;
+    }
+    if examples.inputs.is_empty() && !example_path.exists() {
+        fs::write(&example_path, "").with_context(|| {
+            format!("failed to write {}", example_path.display())
+        })?;
+    }
+    for (i, example) in examples.inputs.iter().enumerate() {
+        let path = get_example_path(year, day, i as u8 + 1);
+        // Never overwrite an example someone already pasted or edited
+        let filled = fs::read_to_string(&path)
+            .is_ok_and(|content| !content.trim().is_empty());
+        if !filled {
+            fs::write(&path, example).with_context(|| {
+                format!("failed to write {}", path.display())
+            })?;
+        }
+    }
 
     // Register in `yearYYYY/mod.rs`
     register_new_day(year, day)?;
@@ -67,7 +119,7 @@ fn main() -> Result<()> {
         .args(["run", "--bin", "registry-tool"])
         .status()
         .context("Failed to run registry-tool")?;
-    
+
     if !status.success() {
         anyhow::bail!("registry-tool failed");
     }
@@ -75,19 +127,35 @@ fn main() -> Result<()> {
     // User guidance
     println!("\nCreated template for year {} day {}", year, day);
     println!("\nNext steps:");
-    println!("  1. Download input or Copy/paste input to: input/year{}/day{:02}.txt", year, day);
     println!(
-        "  2. Implement solution in: aoc-lib/src/year{}/day{:02}.rs",
+        "  1. Download input or Copy/paste input to: {}",
+        get_input_path(year, day)?.display()
+    );
+    if examples.inputs.is_empty() {
+        println!(
+            "  2. Paste the puzzle example into: {}",
+            example_path.display()
+        );
+    } else {
+        println!(
+            "  2. Check the {} example(s) taken from the puzzle page, starting with: {}",
+            examples.inputs.len(),
+            example_path.display()
+        );
+    }
+    println!("  3. Implement solution in: {}", solution_path.display());
+    println!(
+        "  4. Try the example with: cargo run --bin aoc run {} {} --example",
         year, day
     );
-    println!("  3. Run with: cargo run --bin aoc run {} {}", year, day);
+    println!("  5. Run with: cargo run --bin aoc run {} {}", year, day);
 
     Ok(())
 }
 
 // Build the per-year, per-day file location: `aoc-lib/src/yearYYYY/dayDD.rs`
-fn solution_rs_path(year: u16, day: u8) -> PathBuf {
-    PathBuf::from(format!("aoc-lib/src/year{}/day{:02}.rs", year, day))
+fn solution_rs_path(id: PuzzleId) -> PathBuf {
+    PathBuf::from(format!("aoc-lib/src/{}.rs", id))
 }
 
 // Build the per-year module path: `aoc-lib/src/yearYYYY/mod.rs`
@@ -103,8 +171,9 @@ fn register_new_day(year: u16, day: u8) -> Result<()> {
     if !path.exists() {
         let scaffold = build_year_scaffold(year, day);
         if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)
-                .with_context(|| format!("failed to create directory {}", parent.display()))?;
+            fs::create_dir_all(parent).with_context(|| {
+                format!("failed to create directory {}", parent.display())
+            })?;
         }
         fs::write(&path, scaffold)
             .with_context(|| format!("failed to create {}", path.display()))?;
@@ -128,65 +197,206 @@ fn register_new_day(year: u16, day: u8) -> Result<()> {
 // Initial scaffold for a new year module file `yearYYYY/mod.rs`
 fn build_year_scaffold(year: u16, day: u8) -> String {
     let day_mod = format!("mod day{:02};", day);
-    let entry = format!("    (\"{}\", day{:02}::solve),", day, day);
+    let entry = day_entry(day);
+    let day_count = day_count(year);
     format!(
         "// Auto-generated by new-day tool. No edit required\n\
          // Year {year}\n\
          \n\
-         use anyhow::Result;\n\
+         use crate::solution::DayEntry;\n\
+         \n\
+         /// Puzzles in this year's event; the last day has no part 2\n\
+         pub const DAY_COUNT: u8 = {day_count};\n\
          \n\
          {day_mod}\n\
          \n\
          \n\
-         pub const DAYS: &[(&str, fn() -> Result<()>)] =\n\
+         pub const DAYS: &[DayEntry] =\n\
          &[\n\
          {entry}\n\
          ];\n"
     )
 }
 
-// Minimal content for a new `dayDD.rs` file
-fn build_day_stub(year: u16, day: u8) -> String {
+// Remove `--name value` or `--name=value` from the arguments
+fn take_option(args: &mut Vec<String>, name: &str) -> Result<Option<String>> {
+    let prefix = format!("{}=", name);
+    if let Some(i) = args.iter().position(|arg| arg.starts_with(&prefix)) {
+        return Ok(Some(args.remove(i)[prefix.len()..].to_string()));
+    }
+    match args.iter().position(|arg| arg == name) {
+        Some(i) if i + 1 < args.len() => {
+            args.remove(i);
+            Ok(Some(args.remove(i)))
+        }
+        Some(_) => anyhow::bail!("{} needs a value", name),
+        None => Ok(None),
+    }
+}
+
+// Fetch the puzzle page (cached next to the input) for its title and
+// examples. Without a page, e.g. offline or before the puzzle unlocks,
+// the title and example test are left for the user to fill in.
+fn fetch_puzzle_page(year: u16, day: u8) -> Option<String> {
+    println!("Fetching puzzle page for the title and examples...");
+    match load_puzzle(year, day, false) {
+        Ok(page) => Some(page.html),
+        Err(err) => {
+            println!(
+                "Could not fetch the puzzle page ({:#}); fill in the title and example by hand",
+                err
+            );
+            None
+        }
+    }
+}
+
+// The DAYS entry for a day: ("5", day05::TITLE, day05::part1, day05::part2),
+fn day_entry(day: u8) -> String {
     format!(
-        "// Auto-generated day stub. Do not delete solve()\n\
-         // Add you code to solve(), or implement other fn and call from solve().\n\n\
-         use anyhow::Result;\n\
-         use crate::utils;\n\
-         \n\n\
-         // Example template.\n\n\
-         pub fn solve() -> Result<()> {{\n\
-         // Load your input file.\n\
-         \tlet input = utils::load_input({}, {})?;\n\
-         \n\
-         \tlet part1 = solve_part1(&input)?;\n\
-         \tlet part2 = solve_part2(&input)?;\n\
-         \n\
-         \tprintln!(\"Day {} / Year {}\");\n\
-         \tprintln!(\"Part 1: {{}}\", part1);\n\
-         \tprintln!(\"Part 2: {{}}\", part2);\n\
-         \n\
-         \tOk(())\n\
-         }}\n\
-         \n\
-        // Rename _input variable in fn signature back to input after implementing the solution\n\
-         fn solve_part1(_input: &str) -> Result<impl std::fmt::Display> {{\n\
-         \tOk(0)\n\
-         }}\n\
-         \n\
-        // Rename _input variable in fn signature back to input after implementing the solution\n\
-         fn solve_part2(_input: &str) -> Result<impl std::fmt::Display> {{\n\
-         \tOk(0)\n\
-         }}\n",
-        year, day, day, year
+        "    (\"{}\", day{:02}::TITLE, day{:02}::part1, day{:02}::part2),",
+        day, day, day, day
     )
 }
 
+// The `TITLE` const every day file defines for the registry
+fn title_const(title: &str) -> String {
+    format!(
+        "/// Puzzle title, shown by `aoc list` and searched by `aoc find`\n\
+         pub const TITLE: &str = {:?};\n",
+        title
+    )
+}
+
+// Content for a new `dayDD.rs` file
+fn build_day_stub(
+    title: &str,
+    examples: &PuzzleExamples,
+    part2_test: bool,
+) -> String {
+    let mut stub = "// Auto-generated day stub. Do not delete part1() or part2()\n\
+     // Add your code to solve_part1() and solve_part2(); part1() and part2() hand the answers to the runner.\n\n\
+     use anyhow::Result;\n\
+     \n\
+     {title}\
+     \n\n\
+     // Example template.\n\n\
+     pub fn part1(input: &str) -> Result<String> {\n\
+     \tsolve_part1(input).map(|answer| answer.to_string())\n\
+     }\n\
+     \n\
+     pub fn part2(input: &str) -> Result<String> {\n\
+     \tsolve_part2(input).map(|answer| answer.to_string())\n\
+     }\n\
+     \n\
+     // Rename _input variable in fn signature back to input after implementing the solution\n\
+     fn solve_part1(_input: &str) -> Result<impl std::fmt::Display> {\n\
+     \tOk(0)\n\
+     }\n\
+     \n\
+     // Rename _input variable in fn signature back to input after implementing the solution\n\
+     fn solve_part2(_input: &str) -> Result<impl std::fmt::Display> {\n\
+     \tOk(0)\n\
+     }\n\
+     \n\
+     #[cfg(test)]\n\
+     mod tests {\n\
+     \tuse super::*;\n\
+     \tuse crate::aoc_example_test;\n\
+     \n"
+        .replace("{title}", &title_const(title));
+    stub.push_str(&build_example_tests(examples, part2_test));
+    stub.push_str("}\n");
+    stub
+}
+
+// The example consts and tests: the examples found on the puzzle page, or
+// an empty one to paste into. Part 2 uses part 1's example unless the page
+// gave it its own; answers not on the page yet are left as 0. The last
+// day of an event has no part 2, so it gets no part 2 test.
+fn build_example_tests(examples: &PuzzleExamples, part2_test: bool) -> String {
+    if examples.inputs.is_empty() {
+        let mut tests =
+            "\t// Paste the puzzle example and fill in the expected answers\n\
+                         \tconst EXAMPLE: &str = \"\";\n\
+                         \n\
+                         \taoc_example_test!(part1, EXAMPLE, 0);\n"
+                .to_string();
+        if part2_test {
+            tests.push_str("\taoc_example_test!(part2, EXAMPLE, 0);\n");
+        }
+        return tests;
+    }
+
+    let part1 = examples.answers[0].as_ref().map_or(0, |a| a.example);
+    let part2 = examples.answers[1].as_ref().map_or(part1, |a| a.example);
+    let parts = if part2_test {
+        vec![part1, part2]
+    } else {
+        vec![part1]
+    };
+    let mut used = parts.clone();
+    used.dedup();
+
+    let mut tests = String::from(
+        "\t// Taken from the puzzle page; check them against the puzzle\n",
+    );
+    for &index in &used {
+        tests.push_str(&format!(
+            "\tconst {}: &str = {};\n",
+            example_const(index),
+            raw_string(&examples.inputs[index])
+        ));
+    }
+    tests.push('\n');
+    for (part, index) in parts.into_iter().enumerate() {
+        let answer = examples.answers[part]
+            .as_ref()
+            .map_or("0".to_string(), |a| answer_literal(&a.answer));
+        tests.push_str(&format!(
+            "\taoc_example_test!(part{}, {}, {});\n",
+            part + 1,
+            example_const(index),
+            answer
+        ));
+    }
+    tests
+}
+
+// EXAMPLE, EXAMPLE_2, EXAMPLE_3, ... matching dayDD.exampleN.txt
+fn example_const(index: usize) -> String {
+    match index {
+        0 => "EXAMPLE".to_string(),
+        _ => format!("EXAMPLE_{}", index + 1),
+    }
+}
+
+// A raw string literal with enough #s that the text cannot end it
+fn raw_string(text: &str) -> String {
+    let hashes = text
+        .split('"')
+        .skip(1)
+        .map(|after| after.chars().take_while(|&c| c == '#').count() + 1)
+        .max()
+        .unwrap_or(1);
+    let hashes = "#".repeat(hashes);
+    format!("r{hashes}\"{text}\"{hashes}")
+}
+
+// Numbers as they are, anything else as a string literal
+fn answer_literal(answer: &str) -> String {
+    if answer.parse::<i64>().is_ok() {
+        answer.to_string()
+    } else {
+        format!("{:?}", answer)
+    }
+}
 
 // Integrate the new day into an existing `yearYYYY/mod.rs`
 fn integrate_day_into_year_file(src: &str, day: u8) -> Result<String> {
     let mut lines = src.lines().map(|s| s.to_string()).collect::<Vec<_>>();
     let mod_line = format!("mod day{:02};", day);
-    let tuple_line = format!("    (\"{}\", day{:02}::solve),", day, day);
+    let tuple_line = day_entry(day);
 
     // Add `mod dayDD;` if not already present
     if !lines.iter().any(|l| l.trim() == mod_line) {
@@ -207,7 +417,8 @@ fn integrate_day_into_year_file(src: &str, day: u8) -> Result<String> {
         merge_split_closing(&mut lines);
         close_idx = find_days_close(&lines, open_idx);
     }
-    let close_idx = close_idx.ok_or_else(|| anyhow!("could not locate DAYS array closing '];'"))?;
+    let close_idx = close_idx
+        .ok_or_else(|| anyhow!("could not locate DAYS array closing '];'"))?;
 
     // Insert the tuple just above the closing `];` if not already present
     let already_present = lines[(open_idx + 1)..close_idx]
@@ -220,7 +431,7 @@ fn integrate_day_into_year_file(src: &str, day: u8) -> Result<String> {
     Ok(lines.join("\n") + "\n")
 }
 
-// Fix inserting ("DD", dayDD::solve), right before the closing `];` of DAYS,
+// Fix inserting ("DD", dayDD::part1, dayDD::part2), right before the closing `];` of DAYS,
 // Find the last `mod dayNN;` line index
 fn last_mod_index(lines: &[String]) -> Option<usize> {
     lines
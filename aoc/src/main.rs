@@ -1,69 +1,580 @@
 use anyhow::{Context, Result};
-use aoc_lib::SolutionRegistry;
+use aoc_lib::utils::answers::{
+    check_answer, get_answers_path, AnswerStore, CheckStatus,
+};
+use aoc_lib::utils::calendar::{
+    day_count, ensure_unlocked, ensure_valid_year, format_utc, full_year,
+    is_last_day, time_until_unlock, unlock_time,
+};
+use aoc_lib::utils::crypto::{
+    decrypt_file, encrypt_file, encrypted_path, find_inputs, InputKey,
+};
+use aoc_lib::utils::history::{
+    get_history_path, AttemptOutcome, SubmissionHistory,
+};
+use aoc_lib::utils::paths::{input_layout, input_root, workspace_root};
+use aoc_lib::utils::puzzle::has_part_two;
+use aoc_lib::utils::session::{
+    self, mask_token, resolve_session, SessionSource,
+};
+use aoc_lib::utils::{
+    cached_input, ensure_input_with, format_duration, format_remaining,
+    get_example_path, get_input_path, load_puzzle, puzzle_title, render_puzzle,
+    AnswerHint, AocClient, InputSource, PageSource, PartOutcome, RunSummary,
+    SolutionOutput, SubmitOutcome, SummaryRow,
+};
+use aoc_lib::{DaySolver, PartSolver, PuzzleId, SolutionRegistry};
 use clap::{Parser, Subcommand};
 use colored::*;
+use std::io::Write;
+use std::time::Duration;
 
 #[derive(Parser)]
 #[command(name = "aoc")]
 #[command(about = "Advent of Code Solutions Runner", long_about = None)]
 struct Cli {
+    /// AoC session token; overrides AOC_SESSION and saved sessions
+    #[arg(long, global = true, value_name = "TOKEN")]
+    session: Option<String>,
+    /// Named profile for another account (overrides AOC_PROFILE);
+    /// its session, inputs and answers are kept separate
+    #[arg(long, global = true, value_name = "NAME")]
+    profile: Option<String>,
     #[command(subcommand)]
     command: Commands,
 }
 
 #[derive(Subcommand)]
 enum Commands {
-    /// Run a solution for a specific day
+    /// Run a solution for a specific day, a whole year, or everything
     Run {
-        /// Year (e.g., 2024)
-        year: u16,
-        /// Day (1-25)
-        day: u8,
+        /// Puzzle (2024 5, 2024/5, 2024-05, 24d5 or today), or just a year
+        /// to run every registered day of it
+        #[arg(value_name = "PUZZLE", num_args = 1..=2, required_unless_present = "all")]
+        puzzle: Vec<String>,
+        /// Run every registered year and day
+        #[arg(long, conflicts_with = "puzzle")]
+        all: bool,
+        /// Run only this part (1 or 2)
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Read input from this file instead of input/yearYYYY/dayDD.txt ("-" for stdin)
+        #[arg(long, value_name = "PATH", requires = "puzzle")]
+        input: Option<InputSource>,
+        /// Run against example N, input/yearYYYY/dayDD.exampleN.txt (default 1)
+        #[arg(
+            long,
+            value_name = "N",
+            num_args = 0..=1,
+            default_missing_value = "1",
+            requires = "puzzle",
+            conflicts_with = "input"
+        )]
+        example: Option<u8>,
     },
-    /// List all available solutions
+    /// List solutions with their titles, solve status and stars
     List {
         /// Optional year filter
         year: Option<u16>,
     },
-    /// Download input for a specific day (requires AOC_SESSION env var)
+    /// Search puzzle titles across all years (case-insensitive)
+    Find {
+        /// Text to look for, e.g. "keypad"
+        query: String,
+    },
+    /// Download input for a specific day (requires a session token)
     Download {
-        /// Year (e.g., 2024)
-        year: u16,
-        /// Day (1-25)
-        day: u8,
+        /// Puzzle (2024 5, 2024/5, 2024-05, 24d5 or today), or just a year with --all
+        #[arg(value_name = "PUZZLE", num_args = 1..=2, required = true)]
+        puzzle: Vec<String>,
+        /// Download every unlocked day of the year, skipping cached inputs
+        #[arg(long, conflicts_with = "wait")]
+        all: bool,
+        /// If the puzzle is still locked, count down and fetch it the moment it unlocks
+        #[arg(long)]
+        wait: bool,
+    },
+    /// Set up the puzzle that unlocked today: scaffold the day if missing,
+    /// download the input and cache the puzzle text (during an event only)
+    Today,
+    /// Show a day's puzzle description in the terminal, cached next to the input
+    Read {
+        /// Puzzle (2024 5, 2024/5, 2024-05, 24d5 or today)
+        #[arg(value_name = "PUZZLE", num_args = 1..=2, required = true)]
+        puzzle: Vec<String>,
+        /// Fetch the page again even if a cached copy exists
+        #[arg(long)]
+        refresh: bool,
+    },
+    /// Run a day and record its answers as accepted in answers/yearYYYY.toml
+    Record {
+        /// Puzzle (2024 5, 2024/5, 2024-05, 24d5 or today)
+        #[arg(value_name = "PUZZLE", num_args = 1..=2, required = true)]
+        puzzle: Vec<String>,
+        /// Record only this part (1 or 2)
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+    },
+    /// Submit an answer (requires a session token)
+    Submit {
+        /// Puzzle (2024 5, 2024/5, 2024-05, 24d5 or today), then the part
+        /// (1 or 2) and the answer; omit the answer to run the solver and
+        /// submit its answer. Negative answers like -5 are allowed.
+        #[arg(
+            value_name = "PUZZLE PART [ANSWER]",
+            num_args = 2..=4,
+            required = true,
+            allow_negative_numbers = true
+        )]
+        args: Vec<String>,
+        /// Submit even if the history says the answer is known to be wrong
+        #[arg(long)]
+        force: bool,
+    },
+    /// Show submitted answers for a day
+    History {
+        /// Puzzle (2024 5, 2024/5, 2024-05, 24d5 or today)
+        #[arg(value_name = "PUZZLE", num_args = 1..=2, required = true)]
+        puzzle: Vec<String>,
+    },
+    /// Re-run solutions and compare against the recorded answers
+    Check {
+        /// Optional filter: a year, or a puzzle (2024 5, 2024/5, today, ...)
+        #[arg(value_name = "PUZZLE", num_args = 0..=2)]
+        puzzle: Vec<String>,
+    },
+    /// Manage the AoC session token
+    Auth {
+        #[command(subcommand)]
+        action: AuthAction,
+    },
+    /// Convert cached inputs between plain text and encrypted-at-rest
+    Inputs {
+        #[command(subcommand)]
+        action: InputsAction,
+    },
+}
+
+#[derive(Subcommand)]
+enum InputsAction {
+    /// Encrypt every plain input under the input root to dayDD.txt.enc
+    Encrypt {
+        /// Keep the plain files instead of deleting them
+        #[arg(long)]
+        keep: bool,
+    },
+    /// Decrypt every dayDD.txt.enc under the input root back to dayDD.txt
+    Decrypt {
+        /// Keep the encrypted files instead of deleting them
+        #[arg(long)]
+        keep: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum AuthAction {
+    /// Save a session token for the current profile (read from stdin if omitted)
+    Set {
+        /// Session cookie value, with or without "session="
+        token: Option<String>,
     },
+    /// Show which token is in use and where it came from
+    Show,
+    /// Verify the token against the site and print the logged-in user
+    Check,
 }
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
+    if let Some(profile) = cli.profile {
+        session::set_cli_profile(profile)?;
+    }
+    if let Some(token) = cli.session {
+        session::set_cli_session(token);
+    }
 
     match cli.command {
-        Commands::Run { year, day } => run_solution(year, day),
-        Commands::List { year } => list_solutions(year),
-        Commands::Download { year, day } => download_input(year, day),
+        Commands::Run {
+            puzzle,
+            all,
+            part,
+            input,
+            example,
+        } => {
+            if all {
+                return run_many(&SolutionRegistry::available_years(), part);
+            }
+            match select(&puzzle)? {
+                Selection::Puzzle(id) => {
+                    let source = match example {
+                        Some(example) => InputSource::Example(example),
+                        None => input.unwrap_or_default(),
+                    };
+                    run_solution(id, part, &source)
+                }
+                Selection::Year(_) if input.is_some() || example.is_some() => {
+                    anyhow::bail!(
+                        "--input and --example need a day, e.g. 2024 5"
+                    )
+                }
+                Selection::Year(year) => run_many(&[year], part),
+            }
+        }
+        Commands::List { year } => list_solutions(year.map(full_year)),
+        Commands::Find { query } => find_puzzles(&query),
+        Commands::Download { puzzle, all, wait } => {
+            match (select(&puzzle)?, all) {
+                (Selection::Puzzle(id), false) => download_input(id, wait),
+                (Selection::Year(year), true) => download_year(year),
+                (Selection::Year(year), false) => {
+                    anyhow::bail!("Give a day too, or use --all to download every day of {}", year)
+                }
+                (Selection::Puzzle(id), true) => {
+                    anyhow::bail!(
+                        "--all downloads a whole year: use {} --all",
+                        id.year()
+                    )
+                }
+            }
+        }
+        Commands::Today => today(),
+        Commands::Read { puzzle, refresh } => {
+            read_puzzle(puzzle_arg(&puzzle)?, refresh)
+        }
+        Commands::Record { puzzle, part } => {
+            record_answers(puzzle_arg(&puzzle)?, part)
+        }
+        Commands::Check { puzzle } if puzzle.is_empty() => {
+            check_solutions(None, None)
+        }
+        Commands::Check { puzzle } => match select(&puzzle)? {
+            Selection::Year(year) => check_solutions(Some(year), None),
+            Selection::Puzzle(id) => {
+                check_solutions(Some(id.year()), Some(id.day()))
+            }
+        },
+        Commands::Submit { args, force } => {
+            let (id, rest) = PuzzleId::from_args(&args)?;
+            let (part, answer) = match rest {
+                [part] => (part, None),
+                [part, answer] => (part, Some(answer.clone())),
+                _ => {
+                    anyhow::bail!("Usage: aoc submit <PUZZLE> <PART> [ANSWER]")
+                }
+            };
+            let part = match part.as_str() {
+                "1" => 1,
+                "2" => 2,
+                _ => anyhow::bail!("Part must be 1 or 2, got {:?}", part),
+            };
+            submit_answer(id, part, answer, force)
+        }
+        Commands::History { puzzle } => show_history(puzzle_arg(&puzzle)?),
+        Commands::Auth { action } => match action {
+            AuthAction::Set { token } => auth_set(token),
+            AuthAction::Show => auth_show(),
+            AuthAction::Check => auth_check(),
+        },
+        Commands::Inputs { action } => match action {
+            InputsAction::Encrypt { keep } => convert_inputs(true, keep),
+            InputsAction::Decrypt { keep } => convert_inputs(false, keep),
+        },
     }
 }
 
-fn run_solution(year: u16, day: u8) -> Result<()> {
-    if !(2015..=2099).contains(&year) {
-        anyhow::bail!("Year must be between 2015 and 2099 (Advent of Code years)");
+// What positional puzzle args select where a whole year is allowed too:
+// a lone number like `2024` (or `24`) is the year, anything else one puzzle
+enum Selection {
+    Year(u16),
+    Puzzle(PuzzleId),
+}
+
+fn select(args: &[String]) -> Result<Selection> {
+    if let [year] = args {
+        if let Ok(year) = year.parse::<u16>().map(full_year) {
+            ensure_valid_year(year)?;
+            return Ok(Selection::Year(year));
+        }
     }
-    
-    // ToDo: hardcoded: Starting 2025, AoC is now only 12 days
-    //       intentionally leaving range of 1 to 25 so we can still use code for previous years
-    //     **  In future, I'll figure out a way to create a dynamic variable
-    //      that is set as part of the Day 1 template creation
-    if !(1..=25).contains(&day) {
-        anyhow::bail!("Day must be between 1 and 25");
+    puzzle_arg(args).map(Selection::Puzzle)
+}
+
+// The puzzle named by all of a command's positional args
+fn puzzle_arg(args: &[String]) -> Result<PuzzleId> {
+    let (id, rest) = PuzzleId::from_args(args)?;
+    if let Some(extra) = rest.first() {
+        anyhow::bail!("Unexpected argument {:?} after the puzzle", extra);
     }
+    Ok(id)
+}
 
+fn run_solution(
+    id: PuzzleId,
+    part: Option<u8>,
+    source: &InputSource,
+) -> Result<()> {
+    let (year, day) = (id.year(), id.day());
     let solver = SolutionRegistry::get_solver(year, day)
         .with_context(|| format!(
             "No solution found for year {} day {}\n\nTo create this day: cargo run --bin new-day {} {}\nIf the day exists: cargo run --bin registry-tool",
             year, day, year, day
         ))?;
 
-    solver()
+    let parts = parts_to_run(year, day, part)?;
+    let input = aoc_lib::utils::load_input_from(year, day, source)?;
+
+    let mut output = SolutionOutput::new(year, day);
+    let mut total = Duration::ZERO;
+
+    for (part, answer, elapsed) in solve_parts(solver, &input, &parts) {
+        let answer = answer.with_context(|| format!("Part {} failed", part))?;
+        total += elapsed;
+
+        output = match part {
+            1 => output.part1(answer),
+            _ => output.part2(answer),
+        };
+    }
+
+    output.elapsed(total).print();
+
+    Ok(())
+}
+
+// Run every registered day of the given years and print a summary table.
+// A failing day is reported in its row and does not stop the others.
+fn run_many(years: &[u16], part: Option<u8>) -> Result<()> {
+    let mut summary = RunSummary::new();
+
+    for &year in years {
+        ensure_valid_year(year)?;
+
+        let days = SolutionRegistry::available_days(year);
+        if days.is_empty() {
+            println!(
+                "{}",
+                format!("No solutions registered for {}", year).yellow()
+            );
+            continue;
+        }
+
+        for day in days {
+            let Some(solver) = SolutionRegistry::get_solver(year, day) else {
+                continue;
+            };
+
+            let input = match aoc_lib::utils::load_input(year, day) {
+                Ok(input) => input,
+                Err(err) => {
+                    summary.push(SummaryRow::failed(
+                        year,
+                        day,
+                        format!("{:#}", err),
+                    ));
+                    continue;
+                }
+            };
+
+            // --part 2 leaves the last day's row empty
+            let parts = parts_to_run(year, day, part).unwrap_or_default();
+            let mut row = SummaryRow::new(year, day);
+            for (part, answer, elapsed) in solve_parts(solver, &input, &parts) {
+                let outcome = match answer {
+                    Ok(answer) => PartOutcome::Solved { answer, elapsed },
+                    Err(err) => PartOutcome::Failed(format!("{:#}", err)),
+                };
+                match part {
+                    1 => row.part1 = outcome,
+                    _ => row.part2 = outcome,
+                }
+            }
+            summary.push(row);
+        }
+    }
+
+    summary.print();
+
+    if summary.has_failures() {
+        anyhow::bail!("Some solutions failed");
+    }
+    Ok(())
+}
+
+// The requested part, or both; the last day of an event has only part 1
+fn parts_to_run(year: u16, day: u8, part: Option<u8>) -> Result<Vec<u8>> {
+    let last_day = is_last_day(year, day);
+    match part {
+        Some(2) if last_day => anyhow::bail!(
+            "Day {} is the last day of {} and has no part 2; its second star is \
+             awarded on the site once every other star is collected",
+            day,
+            year
+        ),
+        Some(part) => Ok(vec![part]),
+        None if last_day => Ok(vec![1]),
+        None => Ok(vec![1, 2]),
+    }
+}
+
+// Run the given parts against the input, timing each part
+fn solve_parts(
+    solver: DaySolver,
+    input: &str,
+    parts: &[u8],
+) -> Vec<(u8, Result<String>, Duration)> {
+    parts
+        .iter()
+        .filter_map(|&part| Some((part, solver.part(part)?)))
+        .map(|(part, part_solver)| {
+            let (answer, elapsed) =
+                aoc_lib::timed!(run_part(part_solver, input));
+            (part, answer, elapsed)
+        })
+        .collect()
+}
+
+// Run one part, turning a panic (an unwrap, an index out of range) into an
+// error so it fails only that part instead of the whole run
+fn run_part(part_solver: PartSolver, input: &str) -> Result<String> {
+    std::panic::catch_unwind(|| part_solver(input)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string());
+        Err(anyhow::anyhow!("panicked: {}", message))
+    })
+}
+
+// Run a day against its real input and store the answers as accepted
+fn record_answers(id: PuzzleId, part: Option<u8>) -> Result<()> {
+    let (year, day) = (id.year(), id.day());
+    let solver =
+        SolutionRegistry::get_solver(year, day).with_context(|| {
+            format!("No solution found for year {} day {}", year, day)
+        })?;
+    let parts = parts_to_run(year, day, part)?;
+    let input = aoc_lib::utils::load_input(year, day)?;
+    let mut store = AnswerStore::load(year)?;
+
+    for (part, answer, _) in solve_parts(solver, &input, &parts) {
+        let answer = answer.with_context(|| format!("Part {} failed", part))?;
+        println!(
+            "{} {}",
+            format!("Part {}:", part).bright_green(),
+            answer.bold()
+        );
+        store.set(day, part, answer);
+    }
+
+    store.save()?;
+    println!("Recorded answers in {}", get_answers_path(year)?.display());
+
+    Ok(())
+}
+
+// Re-run solvers and report pass/fail/missing against the answer store
+fn check_solutions(
+    year_filter: Option<u16>,
+    day_filter: Option<u8>,
+) -> Result<()> {
+    let years = match year_filter {
+        Some(year) => vec![year],
+        None => SolutionRegistry::available_years(),
+    };
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for year in years {
+        let store = AnswerStore::load(year)?;
+        let days = match day_filter {
+            Some(day) => vec![day],
+            None => SolutionRegistry::available_days(year),
+        };
+
+        for day in days {
+            let label = format!("{} day {:02}", year, day);
+
+            let Some(solver) = SolutionRegistry::get_solver(year, day) else {
+                println!("{}  {}", label, "NO SOLUTION".yellow());
+                missing += 1;
+                continue;
+            };
+            let Ok(input) = aoc_lib::utils::load_input(year, day) else {
+                println!("{}  {}", label, "NO INPUT".yellow());
+                missing += 1;
+                continue;
+            };
+
+            let parts = parts_to_run(year, day, None)?;
+            for (part, answer, elapsed) in solve_parts(solver, &input, &parts) {
+                let prefix = format!("{} part {}", label, part);
+                let time = format_duration(elapsed);
+
+                let answer = match answer {
+                    Ok(answer) => answer,
+                    Err(err) => {
+                        println!(
+                            "{}  {}  {:#}",
+                            prefix,
+                            "FAIL".bright_red(),
+                            err
+                        );
+                        failed += 1;
+                        continue;
+                    }
+                };
+
+                match check_answer(store.get(day, part), &answer) {
+                    CheckStatus::Pass => {
+                        println!(
+                            "{}  {}  ({})",
+                            prefix,
+                            "PASS".bright_green(),
+                            time
+                        );
+                        passed += 1;
+                    }
+                    CheckStatus::Fail { expected } => {
+                        println!(
+                            "{}  {}  expected {}, got {}",
+                            prefix,
+                            "FAIL".bright_red(),
+                            expected,
+                            answer
+                        );
+                        failed += 1;
+                    }
+                    CheckStatus::Missing => {
+                        println!(
+                            "{}  {}  no recorded answer (got {})",
+                            prefix,
+                            "MISSING".yellow(),
+                            answer
+                        );
+                        missing += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    println!();
+    println!(
+        "{} {} passed, {} failed, {} missing",
+        "Check:".bright_cyan().bold(),
+        passed,
+        failed,
+        missing
+    );
+
+    if failed > 0 {
+        anyhow::bail!(
+            "{} answer(s) no longer match the recorded answers",
+            failed
+        );
+    }
+    Ok(())
 }
 
 fn list_solutions(year_filter: Option<u16>) -> Result<()> {
@@ -80,56 +591,103 @@ fn list_solutions(year_filter: Option<u16>) -> Result<()> {
         }
 
         println!("{}", format!("Year {}", year).bright_cyan().bold());
-        println!("{}", "─".repeat(40).bright_black());
-
-        let mut day_ranges = vec![];
-        let mut start = days[0];
-        let mut end = days[0];
-
-        for &day in &days[1..] {
-            if day == end + 1 {
-                end = day;
-            } else {
-                if start == end {
-                    day_ranges.push(format!("{}", start));
-                } else {
-                    day_ranges.push(format!("{}-{}", start, end));
-                }
-                start = day;
-                end = day;
-            }
-        }
+        println!("{}", "─".repeat(56).bright_black());
 
-        if start == end {
-            day_ranges.push(format!("{}", start));
-        } else {
-            day_ranges.push(format!("{}-{}", start, end));
+        // Solved means recorded in answers/ or accepted by AoC
+        let store = AnswerStore::load(year)?;
+        let history = SubmissionHistory::load(year)?;
+        let mut total_stars = 0;
+
+        for &day in &days {
+            // The last day's part 2 is a free star, not a puzzle
+            let parts = if is_last_day(year, day) { 1..=1 } else { 1..=2 };
+            let stars = parts
+                .clone()
+                .filter(|&part| {
+                    store.get(day, part).is_some()
+                        || history.is_solved(day, part)
+                })
+                .count();
+            total_stars += stars;
+
+            let title = SolutionRegistry::title(year, day).unwrap_or("-");
+            let status = match stars {
+                0 => "unsolved".bright_black(),
+                n if n == parts.count() => "solved".bright_green(),
+                _ => "part 1".yellow(),
+            };
+            println!(
+                "Day {:02}  {:<36}  {}  {}",
+                day,
+                title,
+                format!("{:<2}", "*".repeat(stars)).bright_yellow(),
+                status
+            );
         }
 
-        println!("Days: {}", day_ranges.join(", "));
+        println!("{}", "─".repeat(56).bright_black());
+        println!(
+            "{} day{}, {} star{}",
+            days.len(),
+            if days.len() == 1 { "" } else { "s" },
+            total_stars,
+            if total_stars == 1 { "" } else { "s" }
+        );
         println!();
     }
 
     Ok(())
 }
 
-fn download_input(year: u16, day: u8) -> Result<()> {
-    if !(2015..=2099).contains(&year) {
-        anyhow::bail!("Year must be between 2015 and 2099 (Advent of Code years)");
+fn find_puzzles(query: &str) -> Result<()> {
+    let needle = query.trim().to_lowercase();
+    if needle.is_empty() {
+        anyhow::bail!("Search text is empty");
     }
-    
-    // ToDo: hardcoded: Starting 2025, AoC is now only 12 days
-    //       intentionally leaving range of 1 to 25 so we can still use code for previous years
-    //     **  In future, I'll figure out a way to create a dynamic variable
-    //      that is set as part of the Day 1 template creation
-    if !(1..=25).contains(&day) {
-        anyhow::bail!("Day must be between 1 and 25");
+
+    let mut found = 0;
+    for year in SolutionRegistry::available_years() {
+        for day in SolutionRegistry::available_days(year) {
+            let Some(title) = SolutionRegistry::title(year, day) else {
+                continue;
+            };
+            if title.to_lowercase().contains(&needle) {
+                println!(
+                    "{} day {:<2}  {}",
+                    year,
+                    day,
+                    title.bright_white().bold()
+                );
+                found += 1;
+            }
+        }
+    }
+
+    if found == 0 {
+        println!("No puzzle titles match \"{}\"", query.trim());
+    }
+
+    Ok(())
+}
+
+fn download_input(id: PuzzleId, wait: bool) -> Result<()> {
+    let (year, day) = (id.year(), id.day());
+
+    if time_until_unlock(year, day).is_some() {
+        if !wait {
+            if let Err(err) = ensure_unlocked(year, day) {
+                anyhow::bail!(
+                    "{}\n\nRun with --wait to count down and download it as soon as it unlocks.",
+                    err
+                );
+            }
+        }
+        wait_for_unlock(year, day)?;
     }
 
     println!(
         "{}",
-        format!("Downloading input for {} day {}", year, day)
-            .bright_cyan()
+        format!("Downloading input for {} day {}", year, day).bright_cyan()
     );
 
     let content = aoc_lib::utils::ensure_input(year, day)?;
@@ -139,3 +697,490 @@ fn download_input(year: u16, day: u8) -> Result<()> {
 
     Ok(())
 }
+
+fn download_year(year: u16) -> Result<()> {
+    let title = format!("Downloading inputs for {}", year);
+    println!("{}", title.bright_cyan().bold());
+    println!("{}", "─".repeat(title.len()).bright_black());
+
+    // Built on the first download, so a fully cached year needs no session
+    let mut client = None;
+    let (mut downloaded, mut cached) = (0, 0);
+    let mut locked = None;
+    let mut failed = None;
+
+    for day in 1..=day_count(year) {
+        let label = format!("Day {:02}", day);
+
+        if cached_input(year, day)?.is_some() {
+            println!("{}  {}", label, "cached".bright_black());
+            cached += 1;
+            continue;
+        }
+
+        if let Some(remaining) = time_until_unlock(year, day) {
+            println!(
+                "{}  {}  unlocks at {} (in {})",
+                label,
+                format!("{:<10}", "locked").yellow(),
+                format_utc(unlock_time(year, day)),
+                format_remaining(remaining)
+            );
+            locked = Some(day);
+            break;
+        }
+
+        let client = match &client {
+            Some(client) => client,
+            None => client.insert(AocClient::from_env()?),
+        };
+        match ensure_input_with(client, year, day) {
+            Ok(content) => {
+                println!(
+                    "{}  {}  {} lines",
+                    label,
+                    format!("{:<10}", "downloaded").bright_green(),
+                    content.lines().count()
+                );
+                downloaded += 1;
+            }
+            Err(err) => {
+                println!(
+                    "{}  {}  {:#}",
+                    label,
+                    format!("{:<10}", "failed").bright_red(),
+                    err
+                );
+                failed = Some(day);
+                break;
+            }
+        }
+    }
+
+    println!("{}", "─".repeat(title.len()).bright_black());
+    println!("{} downloaded, {} already cached", downloaded, cached);
+    if let Some(day) = locked {
+        println!("Stopped at day {}: not unlocked yet", day);
+    }
+    if let Some(day) = failed {
+        anyhow::bail!("Stopped at day {}: download failed", day);
+    }
+
+    Ok(())
+}
+
+// Count down on one line until the puzzle unlocks
+fn wait_for_unlock(year: u16, day: u8) -> Result<()> {
+    println!(
+        "{}",
+        format!(
+            "{} day {} unlocks at {}",
+            year,
+            day,
+            format_utc(unlock_time(year, day))
+        )
+        .bright_cyan()
+    );
+
+    while let Some(remaining) = time_until_unlock(year, day) {
+        print!(
+            "\r{} {:<16}",
+            "Unlocks in".bright_black(),
+            format_remaining(remaining)
+        );
+        std::io::stdout()
+            .flush()
+            .context("Failed to write countdown")?;
+        std::thread::sleep(remaining.min(Duration::from_secs(1)));
+    }
+    println!("\r{:<30}", "Unlocked!".bright_green());
+
+    // Give the site a moment to publish the input
+    std::thread::sleep(Duration::from_secs(1));
+    Ok(())
+}
+
+fn submit_answer(
+    id: PuzzleId,
+    part: u8,
+    answer: Option<String>,
+    force: bool,
+) -> Result<()> {
+    let (year, day) = (id.year(), id.day());
+    parts_to_run(year, day, Some(part))?;
+
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let part_solver = SolutionRegistry::get_part(year, day, part)
+                .with_context(|| {
+                    format!("No solution found for year {} day {}", year, day)
+                })?;
+            let input = aoc_lib::utils::load_input(year, day)?;
+            part_solver(&input)
+                .with_context(|| format!("Part {} failed", part))?
+        }
+    };
+    let answer = answer.trim().to_string();
+    if answer.is_empty() {
+        anyhow::bail!("Refusing to submit an empty answer");
+    }
+
+    // Don't spend a lockout on an answer AoC has already ruled out
+    let mut history = SubmissionHistory::load(year)?;
+    if let Some(reason) = history.refusal(day, part, &answer) {
+        if !force {
+            anyhow::bail!(
+                "Not submitting: {}\n\nSee: cargo run --bin aoc history {} {}\nUse --force to submit anyway.",
+                reason,
+                year,
+                day
+            );
+        }
+        println!("{}", format!("Submitting anyway: {}", reason).yellow());
+    }
+
+    println!(
+        "{}",
+        format!(
+            "Submitting {} for {} day {} part {}",
+            answer, year, day, part
+        )
+        .bright_cyan()
+    );
+
+    let outcome = aoc_lib::utils::submit_answer(year, day, part, &answer)?;
+    history.record(day, part, &answer, AttemptOutcome::from(&outcome))?;
+
+    match outcome {
+        SubmitOutcome::Correct => {
+            println!("{}", "That's the right answer!".bright_green().bold());
+            let mut store = AnswerStore::load(year)?;
+            store.set(day, part, answer);
+            store.save()?;
+            println!("Recorded in {}", get_answers_path(year)?.display());
+        }
+        SubmitOutcome::Wrong { hint, wait } => {
+            let hint = match hint {
+                Some(AnswerHint::TooHigh) => " (too high)",
+                Some(AnswerHint::TooLow) => " (too low)",
+                None => "",
+            };
+            println!(
+                "{}",
+                format!("That's not the right answer{}", hint).bright_red()
+            );
+            if let Some(wait) = wait {
+                println!("Wait {} before trying again", format_remaining(wait));
+            }
+        }
+        SubmitOutcome::RateLimited { wait } => {
+            let wait = wait
+                .map(format_remaining)
+                .unwrap_or_else(|| "a while".to_string());
+            println!(
+                "{}",
+                format!("Answered too recently; wait {}", wait).yellow()
+            );
+        }
+        SubmitOutcome::AlreadySolved => {
+            println!(
+                "{}",
+                "This part is already solved, or not unlocked yet".yellow()
+            );
+        }
+        SubmitOutcome::Unknown(text) => {
+            println!("{}", "Unrecognised response from AoC:".yellow());
+            println!("{}", text);
+        }
+    }
+
+    Ok(())
+}
+
+// The morning routine for the puzzle that unlocked last (midnight EST):
+// scaffold it with new-day if it has no solution file, download its input,
+// cache the puzzle text and show where everything is
+fn today() -> Result<()> {
+    let id = PuzzleId::today()?;
+    let (year, day) = (id.year(), id.day());
+
+    let solution_path = workspace_root().join(format!("aoc-lib/src/{}.rs", id));
+    let scaffolded = !solution_path.exists();
+    if scaffolded {
+        println!(
+            "{}",
+            format!("Creating {} day {} with new-day", year, day).bright_cyan()
+        );
+        scaffold_day(id)?;
+        println!();
+    }
+
+    let input = aoc_lib::utils::ensure_input(year, day)?;
+    let page = load_puzzle(year, day, false)?;
+    if let PageSource::Offline(err) = &page.source {
+        eprintln!(
+            "{}",
+            format!(
+                "Could not fetch the puzzle ({}); using the cached copy",
+                err
+            )
+            .yellow()
+        );
+    }
+
+    let heading = match puzzle_title(&page.html) {
+        Some(title) => format!("{} day {}: {}", year, day, title),
+        None => format!("{} day {}", year, day),
+    };
+    println!("{}", heading.bright_cyan().bold());
+    println!("{}", "─".repeat(heading.chars().count()).bright_black());
+
+    // With an input key configured only the encrypted copy is stored
+    let input_path = get_input_path(year, day)?;
+    let input_path = if input_path.exists() {
+        input_path
+    } else {
+        encrypted_path(&input_path)
+    };
+    let created = if scaffolded { " (new)" } else { "" };
+    println!("Solution  {}{}", solution_path.display(), created);
+    println!(
+        "Input     {} ({} lines)",
+        input_path.display(),
+        input.lines().count()
+    );
+    println!("Puzzle    {}", page.path.display());
+    let example_path = get_example_path(year, day, 1);
+    if example_path.exists() {
+        println!("Example   {}", example_path.display());
+    }
+
+    println!();
+    println!("Read it with: cargo run --bin aoc read today");
+    println!("Run it with:  cargo run --bin aoc run today");
+
+    Ok(())
+}
+
+// Run new-day from the workspace root, handing on the --session and
+// --profile given to this command (new-day fetches the puzzle page)
+fn scaffold_day(id: PuzzleId) -> Result<()> {
+    let mut new_day = std::process::Command::new("cargo");
+    new_day
+        .args(["run", "--quiet", "--bin", "new-day", "--"])
+        .arg(id.to_string())
+        .current_dir(workspace_root());
+    if let Some(resolved) = resolve_session()? {
+        if resolved.source == SessionSource::Flag {
+            new_day.env("AOC_SESSION", resolved.token);
+        }
+    }
+    if let Some(profile) = session::active_profile()? {
+        new_day.env("AOC_PROFILE", profile);
+    }
+
+    let status = new_day.status().context("Failed to run new-day")?;
+    if !status.success() {
+        anyhow::bail!("new-day failed for {}", id);
+    }
+    Ok(())
+}
+
+fn read_puzzle(id: PuzzleId, refresh: bool) -> Result<()> {
+    let (year, day) = (id.year(), id.day());
+
+    let page = load_puzzle(year, day, refresh)?;
+    if let PageSource::Offline(err) = &page.source {
+        eprintln!(
+            "{}",
+            format!(
+                "Could not fetch the puzzle ({}); showing the cached copy",
+                err
+            )
+            .yellow()
+        );
+    }
+
+    print!("{}", render_puzzle(&page.html));
+    println!("{}", "─".repeat(40).bright_black());
+    let note = match page.source {
+        PageSource::Downloaded => "Saved to",
+        PageSource::Cached | PageSource::Offline(_) => "Cached at",
+    };
+    println!(
+        "{}",
+        format!("{} {}", note, page.path.display()).bright_black()
+    );
+    if !has_part_two(&page.html) {
+        println!(
+            "{}",
+            "Part 2 appears here once part 1 is solved (use --refresh to fetch it now)"
+                .bright_black()
+        );
+    }
+
+    Ok(())
+}
+
+fn show_history(id: PuzzleId) -> Result<()> {
+    let (year, day) = (id.year(), id.day());
+    let history = SubmissionHistory::load(year)?;
+    let attempts = history.attempts(day, None);
+
+    println!(
+        "{}",
+        format!("Day {} / Year {}", day, year).bright_cyan().bold()
+    );
+    println!("{}", "─".repeat(40).bright_black());
+
+    if attempts.is_empty() {
+        println!(
+            "No submissions recorded in {}",
+            get_history_path(year)?.display()
+        );
+        return Ok(());
+    }
+
+    for attempt in attempts {
+        let outcome = match attempt.outcome {
+            AttemptOutcome::Correct => attempt.outcome.label().bright_green(),
+            outcome if outcome.is_rejected() => outcome.label().bright_red(),
+            outcome => outcome.label().yellow(),
+        };
+        println!(
+            "{}  part {}  {:<20}  {}",
+            format_utc(attempt.submitted_at).bright_black(),
+            attempt.part,
+            attempt.answer,
+            outcome
+        );
+    }
+
+    Ok(())
+}
+
+fn profile_label() -> Result<String> {
+    Ok(session::active_profile()?.unwrap_or_else(|| "default".to_string()))
+}
+
+fn auth_set(token: Option<String>) -> Result<()> {
+    let token = match token {
+        Some(token) => token,
+        None => {
+            println!("Paste your AoC session cookie and press Enter:");
+            let mut line = String::new();
+            std::io::stdin()
+                .read_line(&mut line)
+                .context("Failed to read session token from stdin")?;
+            line
+        }
+    };
+
+    let profile = session::active_profile()?;
+    let path = session::save_session(profile.as_deref(), &token)?;
+    println!(
+        "{}",
+        format!("Saved session for profile '{}'", profile_label()?)
+            .bright_green()
+    );
+    println!("Stored in {}", path.display());
+    println!("Verify it with: cargo run --bin aoc auth check");
+
+    Ok(())
+}
+
+fn auth_show() -> Result<()> {
+    println!("{}", "AoC session".bright_cyan().bold());
+    println!("{}", "─".repeat(40).bright_black());
+    println!("Profile:  {}", profile_label()?);
+
+    match resolve_session()? {
+        Some(resolved) => {
+            println!("Token:    {}", mask_token(&resolved.token));
+            println!("Source:   {}", resolved.source);
+        }
+        None => {
+            let path = session::get_session_path(
+                session::active_profile()?.as_deref(),
+            )?;
+            println!("Token:    {}", "not set".yellow());
+            println!();
+            println!(
+                "Pass --session, set AOC_SESSION, or save one to {} with:",
+                path.display()
+            );
+            println!("    cargo run --bin aoc auth set");
+        }
+    }
+
+    Ok(())
+}
+
+fn auth_check() -> Result<()> {
+    let client = AocClient::from_env()?;
+    println!(
+        "{}",
+        format!("Checking session for profile '{}'", profile_label()?)
+            .bright_cyan()
+    );
+
+    match client.fetch_user()? {
+        Some(user) => {
+            let stars = user
+                .stars
+                .map(|stars| format!(" ({}*)", stars))
+                .unwrap_or_default();
+            println!(
+                "{}",
+                format!("Logged in as {}{}", user.name, stars).bright_green()
+            );
+            Ok(())
+        }
+        None => anyhow::bail!(
+            "The site does not recognise this session token; it has probably expired.\n\
+             Log in again and save the new cookie with: cargo run --bin aoc auth set"
+        ),
+    }
+}
+
+fn convert_inputs(encrypt: bool, keep: bool) -> Result<()> {
+    let key = InputKey::load()?.context(
+        "No input key configured. Set AOC_INPUT_KEY, or put a secret in ~/.config/aoc/input.key",
+    )?;
+    let root = input_root();
+    let files = find_inputs(&root, input_layout(), !encrypt)?;
+
+    let title = format!(
+        "{} inputs in {}",
+        if encrypt { "Encrypting" } else { "Decrypting" },
+        root.display()
+    );
+    println!("{}", title.bright_cyan().bold());
+    println!("{}", "─".repeat(title.chars().count()).bright_black());
+
+    for path in &files {
+        let target = if encrypt {
+            encrypt_file(&key, path)?
+        } else {
+            decrypt_file(&key, path)?
+        };
+        if !keep {
+            std::fs::remove_file(path).with_context(|| {
+                format!("Failed to remove {}", path.display())
+            })?;
+        }
+        let shown = target.strip_prefix(&root).unwrap_or(&target);
+        println!("{}", shown.display());
+    }
+
+    println!("{}", "─".repeat(title.chars().count()).bright_black());
+    println!(
+        "{} file{} {}",
+        files.len(),
+        if files.len() == 1 { "" } else { "s" },
+        if encrypt { "encrypted" } else { "decrypted" }
+    );
+
+    Ok(())
+}
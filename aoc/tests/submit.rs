@@ -0,0 +1,62 @@
+// aoc/tests/submit.rs
+// `aoc submit` end to end: the real binary against the mock server, in a
+// scratch workspace so history and answers stay out of the repo.
+
+#[path = "../../aoc-lib/tests/common/mod.rs"]
+mod common;
+
+use common::ScratchDir;
+use mock_aoc::{MockAoc, MockConfig};
+use std::path::Path;
+use std::process::{Command, Output};
+
+// A directory the binary takes for the workspace root
+fn scratch_workspace(name: &str) -> ScratchDir {
+    let dir = ScratchDir::new(&format!("cli-{}", name));
+    std::fs::create_dir_all(dir.join("aoc-lib")).unwrap();
+    std::fs::write(dir.join("Cargo.toml"), "[workspace]\n").unwrap();
+    dir
+}
+
+fn aoc(server: &MockAoc, workspace: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .current_dir(workspace)
+        .env("AOC_BASE_URL", server.base_url())
+        .env("AOC_SESSION", "test-session")
+        .env("AOC_CONFIG_DIR", workspace.join("config"))
+        .env("AOC_MIN_INTERVAL", "0")
+        .env("AOC_CONTACT", "tests")
+        .env_remove("AOC_PROFILE")
+        .env_remove("AOC_INPUT_KEY")
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn negative_answers_can_be_submitted() {
+    let server = MockAoc::start(MockConfig::default()).unwrap();
+    let workspace = scratch_workspace("negative");
+
+    for args in [
+        ["submit", "2024", "1", "1", "-5"].as_slice(),
+        &["submit", "2024/1", "2", "-7"],
+    ] {
+        let output = aoc(&server, workspace.path(), args);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(output.status.success(), "{:?}: {}", args, stderr);
+    }
+
+    let bodies: Vec<String> =
+        server.requests().into_iter().map(|r| r.body).collect();
+    assert!(
+        bodies[0].contains("level=1") && bodies[0].contains("answer=-5"),
+        "{:?}",
+        bodies
+    );
+    assert!(
+        bodies[1].contains("level=2") && bodies[1].contains("answer=-7"),
+        "{:?}",
+        bodies
+    );
+}
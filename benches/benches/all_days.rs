@@ -1,17 +1,30 @@
-use aoc_lib::SolutionRegistry;
+use aoc_lib::{utils, SolutionRegistry};
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
+// Each part is registered as its own benchmark ("2024/day01/part1"), so one
+// part can be targeted with a filter: cargo bench -- 2024/day01/part1
 fn bench_all_solutions(c: &mut Criterion) {
     // Benchmark all available years and days
     for year in SolutionRegistry::available_years() {
         let days = SolutionRegistry::available_days(year);
 
         for day in days {
-            if let Some(solver) = SolutionRegistry::get_solver(year, day) {
-                c.bench_function(&format!("{}/day{:02}", year, day), |b| {
+            let Some(solver) = SolutionRegistry::get_solver(year, day) else {
+                continue;
+            };
+
+            // Load input once, outside the timed loop; skip days without input
+            let Ok(input) = utils::load_input(year, day) else {
+                eprintln!("Skipping {}/day{:02}: no input file", year, day);
+                continue;
+            };
+
+            for (part, part_solver) in [(1, solver.part1), (2, solver.part2)] {
+                let id = format!("{}/day{:02}/part{}", year, day, part);
+                c.bench_function(&id, |b| {
                     b.iter(|| {
                         // Run solver and ignore errors in benchmark
-                        let _ = black_box(solver());
+                        let _ = black_box(part_solver(black_box(&input)));
                     });
                 });
             }
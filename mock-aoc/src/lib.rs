@@ -0,0 +1,658 @@
+// mock-aoc/src/lib.rs
+// Local stand-in for adventofcode.com, for integration tests and manual runs.
+// Standard library only: one background thread accepts connections and
+// answers from canned state, mimicking the real site's responses.
+
+use std::collections::BTreeMap;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// Body the real site returns for inputs and puzzles that are not unlocked yet
+pub const NOT_UNLOCKED: &str = "Please don't repeatedly request this endpoint before it unlocks! \
+The calendar countdown is synchronized with the server time; the link will be enabled on the \
+calendar the instant this puzzle becomes available.\n";
+
+/// Body the real site returns for an input request without a session
+pub const LOGIN_REQUIRED: &str =
+    "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n";
+
+/// A puzzle the mock server knows about
+#[derive(Debug, Clone)]
+pub struct MockPuzzle {
+    pub title: String,
+    pub input: String,
+    pub answers: [String; 2],
+    /// Example shown in the puzzle text, with its answer for each part
+    pub example: String,
+    pub example_answers: [String; 2],
+    /// Locked puzzles answer 404, like the real site before midnight EST
+    pub unlocked: bool,
+}
+
+impl MockPuzzle {
+    pub fn new(title: &str, input: &str, part1: &str, part2: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            input: input.to_string(),
+            answers: [part1.to_string(), part2.to_string()],
+            example: String::new(),
+            example_answers: [String::new(), String::new()],
+            unlocked: true,
+        }
+    }
+
+    pub fn with_example(
+        mut self,
+        example: &str,
+        part1: &str,
+        part2: &str,
+    ) -> Self {
+        self.example = example.to_string();
+        self.example_answers = [part1.to_string(), part2.to_string()];
+        self
+    }
+
+    pub fn locked(mut self) -> Self {
+        self.unlocked = false;
+        self
+    }
+}
+
+/// Canned state the server answers from
+#[derive(Debug, Clone)]
+pub struct MockConfig {
+    /// Session token treated as logged in
+    pub session: String,
+    /// Session token treated as expired: answered with the HTML login page
+    pub expired_session: String,
+    /// Name shown as the logged-in user
+    pub user: String,
+    /// Lockout after a wrong answer; submissions inside it are rate-limited
+    pub answer_cooldown: Duration,
+    pub puzzles: BTreeMap<(u16, u8), MockPuzzle>,
+}
+
+impl Default for MockConfig {
+    fn default() -> Self {
+        Self {
+            session: "test-session".to_string(),
+            expired_session: "expired-session".to_string(),
+            user: "Mock User".to_string(),
+            answer_cooldown: Duration::from_secs(60),
+            puzzles: BTreeMap::new(),
+        }
+        .with_puzzle(
+            2024,
+            1,
+            MockPuzzle::new("Mock Puzzle", "3   4\n4   3\n", "11", "31")
+                .with_example("3   4\n4   3\n2   5\n", "11", "31"),
+        )
+        .with_puzzle(
+            2024,
+            25,
+            MockPuzzle::new("Locked Puzzle", "", "", "").locked(),
+        )
+    }
+}
+
+impl MockConfig {
+    pub fn with_puzzle(
+        mut self,
+        year: u16,
+        day: u8,
+        puzzle: MockPuzzle,
+    ) -> Self {
+        self.puzzles.insert((year, day), puzzle);
+        self
+    }
+}
+
+/// A request the server received, for assertions in tests
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    pub session: Option<String>,
+    pub user_agent: Option<String>,
+    pub body: String,
+}
+
+struct State {
+    config: MockConfig,
+    // Number of parts solved per puzzle
+    solved: BTreeMap<(u16, u8), u8>,
+    locked_until: Option<Instant>,
+    requests: Vec<RecordedRequest>,
+    // Requests still to answer with HTTP 500
+    failures: u32,
+}
+
+/// A running mock server; stops when dropped
+pub struct MockAoc {
+    addr: SocketAddr,
+    state: Arc<Mutex<State>>,
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl MockAoc {
+    /// Start on 127.0.0.1 with a free port chosen by the OS
+    pub fn start(config: MockConfig) -> io::Result<Self> {
+        Self::bind("127.0.0.1:0", config)
+    }
+
+    /// Start on a specific address, e.g. "127.0.0.1:3000"
+    pub fn bind(addr: &str, config: MockConfig) -> io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let addr = listener.local_addr()?;
+        let state = Arc::new(Mutex::new(State {
+            config,
+            solved: BTreeMap::new(),
+            locked_until: None,
+            requests: Vec::new(),
+            failures: 0,
+        }));
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let handle = {
+            let state = Arc::clone(&state);
+            let stop = Arc::clone(&stop);
+            std::thread::spawn(move || {
+                for stream in listener.incoming() {
+                    if stop.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    if let Ok(stream) = stream {
+                        // A broken client connection must not stop the server
+                        let _ = handle_connection(stream, &state);
+                    }
+                }
+            })
+        };
+
+        Ok(Self {
+            addr,
+            state,
+            stop,
+            handle: Some(handle),
+        })
+    }
+
+    /// Base URL to hand to `AocClient` or AOC_BASE_URL
+    pub fn base_url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Every request received so far, oldest first
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        lock(&self.state).requests.clone()
+    }
+
+    /// Answer the next `count` requests with HTTP 500, like an overloaded site
+    pub fn fail_next(&self, count: u32) {
+        lock(&self.state).failures = count;
+    }
+
+    /// Block until the server thread exits (used by the binary)
+    pub fn wait(mut self) {
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for MockAoc {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        // Wake the accept loop so it sees the stop flag
+        let _ = TcpStream::connect(self.addr);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn lock(state: &Mutex<State>) -> std::sync::MutexGuard<'_, State> {
+    state
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+struct Response {
+    status: u16,
+    content_type: &'static str,
+    body: String,
+}
+
+impl Response {
+    fn text(status: u16, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            content_type: "text/plain",
+            body: body.into(),
+        }
+    }
+
+    fn html(status: u16, body: String) -> Self {
+        Self {
+            status,
+            content_type: "text/html",
+            body,
+        }
+    }
+}
+
+fn handle_connection(
+    stream: TcpStream,
+    state: &Mutex<State>,
+) -> io::Result<()> {
+    stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line)? == 0 {
+        return Ok(());
+    }
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let path = parts.next().unwrap_or("/").to_string();
+
+    let mut headers = BTreeMap::new();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.insert(
+                name.trim().to_ascii_lowercase(),
+                value.trim().to_string(),
+            );
+        }
+    }
+
+    let length = headers
+        .get("content-length")
+        .and_then(|l| l.parse::<usize>().ok())
+        .unwrap_or(0);
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+
+    let session = headers.get("cookie").and_then(|cookie| {
+        cookie
+            .split(';')
+            .find_map(|c| c.trim().strip_prefix("session="))
+            .map(String::from)
+    });
+
+    let request = RecordedRequest {
+        method,
+        path,
+        session,
+        user_agent: headers.get("user-agent").cloned(),
+        body: String::from_utf8_lossy(&body).into_owned(),
+    };
+
+    let response = {
+        let mut state = lock(state);
+        state.requests.push(request.clone());
+        if state.failures > 0 {
+            state.failures -= 1;
+            Response::text(500, "Internal Server Error")
+        } else {
+            route(&mut state, &request)
+        }
+    };
+
+    let mut stream = stream;
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: {}; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        reason(response.status),
+        response.content_type,
+        response.body.len(),
+        response.body
+    )?;
+    stream.flush()
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        _ => "Internal Server Error",
+    }
+}
+
+enum Session {
+    Valid,
+    Expired,
+    Missing,
+}
+
+fn session_state(state: &State, request: &RecordedRequest) -> Session {
+    match request.session.as_deref() {
+        Some(s) if s == state.config.session => Session::Valid,
+        Some(s) if s == state.config.expired_session => Session::Expired,
+        _ => Session::Missing,
+    }
+}
+
+fn route(state: &mut State, request: &RecordedRequest) -> Response {
+    let path = request.path.split('?').next().unwrap_or_default();
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let method = request.method.as_str();
+
+    match (method, segments.as_slice()) {
+        ("GET", [""]) => calendar_page(state, request, None),
+        ("GET", [year]) => match year.parse() {
+            Ok(year) => calendar_page(state, request, Some(year)),
+            Err(_) => Response::text(404, "404 Not Found"),
+        },
+        ("GET", [year, "day", day]) => {
+            with_puzzle(state, year, day, |state, key| {
+                puzzle_page(state, request, key)
+            })
+        }
+        ("GET", [year, "day", day, "input"]) => {
+            with_puzzle(state, year, day, |state, key| {
+                input_response(state, request, key)
+            })
+        }
+        ("POST", [year, "day", day, "answer"]) => {
+            with_puzzle(state, year, day, |state, key| {
+                answer_response(state, request, key)
+            })
+        }
+        ("GET", [year, "leaderboard", "private", "view", id])
+            if id.ends_with(".json") =>
+        {
+            leaderboard_response(state, request, year)
+        }
+        _ => Response::text(404, "404 Not Found"),
+    }
+}
+
+// Resolve a year/day pair; unknown or locked puzzles get the site's 404
+fn with_puzzle(
+    state: &mut State,
+    year: &str,
+    day: &str,
+    handler: impl FnOnce(&mut State, (u16, u8)) -> Response,
+) -> Response {
+    let key = match (year.parse::<u16>(), day.parse::<u8>()) {
+        (Ok(year), Ok(day)) => (year, day),
+        _ => return Response::text(404, "404 Not Found"),
+    };
+    match state.config.puzzles.get(&key) {
+        Some(puzzle) if puzzle.unlocked => handler(state, key),
+        _ => Response::text(404, NOT_UNLOCKED),
+    }
+}
+
+fn input_response(
+    state: &State,
+    request: &RecordedRequest,
+    key: (u16, u8),
+) -> Response {
+    match session_state(state, request) {
+        Session::Valid => {
+            Response::text(200, state.config.puzzles[&key].input.clone())
+        }
+        Session::Expired => Response::html(200, login_page()),
+        Session::Missing => Response::text(400, LOGIN_REQUIRED),
+    }
+}
+
+fn puzzle_page(
+    state: &State,
+    request: &RecordedRequest,
+    key: (u16, u8),
+) -> Response {
+    let puzzle = &state.config.puzzles[&key];
+    let logged_in = matches!(session_state(state, request), Session::Valid);
+    let solved = if logged_in {
+        state.solved.get(&key).copied().unwrap_or(0)
+    } else {
+        0
+    };
+
+    let mut main = article(
+        &format!("--- Day {}: {} ---", key.1, escape(&puzzle.title)),
+        puzzle,
+        0,
+    );
+    if solved >= 1 {
+        main.push_str(&answer_paragraph(&puzzle.answers[0]));
+        main.push_str(&article("--- Part Two ---", puzzle, 1));
+    }
+    if solved >= 2 {
+        main.push_str(&answer_paragraph(&puzzle.answers[1]));
+    }
+
+    let title = format!("Day {} - Advent of Code {}", key.1, key.0);
+    Response::html(200, page(state, request, &title, &main))
+}
+
+fn answer_response(
+    state: &mut State,
+    request: &RecordedRequest,
+    key: (u16, u8),
+) -> Response {
+    if !matches!(session_state(state, request), Session::Valid) {
+        return Response::html(200, login_page());
+    }
+
+    let form = parse_form(&request.body);
+    let level = form.get("level").and_then(|l| l.parse::<u8>().ok());
+    let answer = form
+        .get("answer")
+        .map(|a| a.trim().to_string())
+        .unwrap_or_default();
+
+    let now = Instant::now();
+    let message = if let Some(until) =
+        state.locked_until.filter(|until| *until > now)
+    {
+        let left = until.duration_since(now).as_secs().max(1);
+        format!(
+            "You gave an answer too recently; you have to wait after submitting an answer \
+             before trying again.  You have {} left to wait.",
+            if left >= 60 {
+                format!("{}m {}s", left / 60, left % 60)
+            } else {
+                format!("{}s", left)
+            }
+        )
+    } else {
+        let solved = state.solved.get(&key).copied().unwrap_or(0);
+        let expected = &state.config.puzzles[&key].answers;
+        match level {
+            Some(level) if level == solved + 1 && level <= 2 => {
+                let correct = &expected[usize::from(level - 1)];
+                if answer == *correct {
+                    state.solved.insert(key, level);
+                    "That's the right answer!  You are <em>one gold star</em> closer.".to_string()
+                } else {
+                    let hint = match (answer.parse::<i128>(), correct.parse::<i128>()) {
+                        (Ok(a), Ok(c)) if a > c => " your answer is too high.",
+                        (Ok(a), Ok(c)) if a < c => " your answer is too low.",
+                        _ => "",
+                    };
+                    let cooldown = state.config.answer_cooldown;
+                    state.locked_until = Some(now + cooldown);
+                    format!(
+                        "That's not the right answer;{}  If you're stuck, make sure you're \
+                         using the full input data.  Please wait {} before trying again.",
+                        hint,
+                        describe_wait(cooldown)
+                    )
+                }
+            }
+            _ => "You don't seem to be solving the right level.  Did you already complete it?"
+                .to_string(),
+        }
+    };
+
+    let main = format!(
+        "<article><p>{} <a href=\"/{}/day/{}\">[Return to Day {}]</a></p></article>\n",
+        message, key.0, key.1, key.1
+    );
+    let title = format!("Day {} - Advent of Code {}", key.1, key.0);
+    Response::html(200, page(state, request, &title, &main))
+}
+
+fn calendar_page(
+    state: &State,
+    request: &RecordedRequest,
+    year: Option<u16>,
+) -> Response {
+    let main = "<pre class=\"calendar\">Mock calendar</pre>\n";
+    let title = match year {
+        Some(year) => format!("Advent of Code {}", year),
+        None => "Advent of Code".to_string(),
+    };
+    Response::html(200, page(state, request, &title, main))
+}
+
+fn leaderboard_response(
+    state: &State,
+    request: &RecordedRequest,
+    year: &str,
+) -> Response {
+    if !matches!(session_state(state, request), Session::Valid) {
+        return Response::html(200, login_page());
+    }
+    let stars: u8 = state.solved.values().sum();
+    Response {
+        status: 200,
+        content_type: "application/json",
+        body: format!(
+            "{{\"event\":\"{}\",\"owner_id\":1,\"members\":{{\"1\":{{\"id\":1,\"name\":\"{}\",\"stars\":{},\"local_score\":0,\"completion_day_level\":{{}}}}}}}}",
+            year, state.config.user, stars
+        ),
+    }
+}
+
+fn article(heading: &str, puzzle: &MockPuzzle, part: usize) -> String {
+    let mut html =
+        format!("<article class=\"day-desc\"><h2>{}</h2>\n", heading);
+    html.push_str(
+        "<p>This is a <em>mock</em> puzzle served for testing.</p>\n",
+    );
+    if !puzzle.example.is_empty() {
+        html.push_str("<p>For example:</p>\n");
+        html.push_str(&format!(
+            "<pre><code>{}</code></pre>\n",
+            escape(&puzzle.example)
+        ));
+        html.push_str(&format!(
+            "<p>In this example, the answer is <code><em>{}</em></code>.</p>\n",
+            escape(&puzzle.example_answers[part])
+        ));
+    }
+    html.push_str("</article>\n");
+    html
+}
+
+fn answer_paragraph(answer: &str) -> String {
+    format!(
+        "<p>Your puzzle answer was <code>{}</code>.</p>\n",
+        escape(answer)
+    )
+}
+
+fn page(
+    state: &State,
+    request: &RecordedRequest,
+    title: &str,
+    main: &str,
+) -> String {
+    let user = match session_state(state, request) {
+        Session::Valid => {
+            let stars: u8 = state.solved.values().sum();
+            format!(
+                "<div class=\"user\">{} <span class=\"star-count\">{}*</span></div>",
+                escape(&state.config.user),
+                stars
+            )
+        }
+        _ => "<div><a href=\"/auth/login\">[Log In]</a></div>".to_string(),
+    };
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en-us\">\n<head>\n<meta charset=\"utf-8\"/>\n\
+         <title>{}</title>\n</head>\n<body>\n<header><h1 class=\"title-global\">\
+         <a href=\"/\">Advent of Code</a></h1>{}</header>\n<main>\n{}</main>\n</body>\n</html>\n",
+        escape(title),
+        user,
+        main
+    )
+}
+
+// The page the real site serves when the session cookie has expired
+fn login_page() -> String {
+    "<!DOCTYPE html>\n<html lang=\"en-us\">\n<head>\n<meta charset=\"utf-8\"/>\n\
+     <title>Advent of Code</title>\n</head>\n<body>\n<main>\n<p>To play, please identify \
+     yourself via one of these services:</p>\n<p><a href=\"/auth/github\">[GitHub]</a> \
+     <a href=\"/auth/google\">[Google]</a></p>\n</main>\n</body>\n</html>\n"
+        .to_string()
+}
+
+fn describe_wait(wait: Duration) -> String {
+    let secs = wait.as_secs();
+    match secs {
+        60 => "one minute".to_string(),
+        s if s % 60 == 0 => format!("{} minutes", s / 60),
+        1 => "one second".to_string(),
+        s => format!("{} seconds", s),
+    }
+}
+
+fn parse_form(body: &str) -> BTreeMap<String, String> {
+    body.split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(k, v)| (url_decode(k), url_decode(v)))
+        .collect()
+}
+
+fn url_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => out.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let hex =
+                    std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap_or("");
+                match u8::from_str_radix(hex, 16) {
+                    Ok(b) => {
+                        out.push(b);
+                        i += 2;
+                    }
+                    Err(_) => out.push(b'%'),
+                }
+            }
+            b => out.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
@@ -0,0 +1,26 @@
+// mock-aoc/src/main.rs
+// Run the mock server by hand: `cargo run --bin mock-aoc [ADDR]`
+// then point the CLI at it with AOC_BASE_URL and AOC_SESSION.
+
+use mock_aoc::{MockAoc, MockConfig};
+
+fn main() -> std::io::Result<()> {
+    let addr = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "127.0.0.1:3000".to_string());
+    let config = MockConfig::default();
+    let session = config.session.clone();
+    let expired = config.expired_session.clone();
+
+    let server = MockAoc::bind(&addr, config)?;
+    println!("Mock Advent of Code listening on {}", server.base_url());
+    println!();
+    println!("  export AOC_BASE_URL={}", server.base_url());
+    println!("  export AOC_SESSION={}", session);
+    println!();
+    println!("Session \"{}\" behaves like an expired cookie.", expired);
+    println!("Puzzles: 2024 day 1 (unlocked), 2024 day 25 (locked)");
+
+    server.wait();
+    Ok(())
+}
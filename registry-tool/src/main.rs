@@ -6,53 +6,75 @@ use anyhow::{Context, Result};
 use std::fs;
 use std::path::PathBuf;
 
+// Put before each generated module declaration in lib.rs
+const RUSTFMT_SKIP: &str = "#[rustfmt::skip]";
+
 fn main() -> Result<()> {
     println!("Scanning for year modules...");
-    
+
     let years = scan_years()?;
-    
+
     if years.is_empty() {
         println!("WARNING: No year modules found in aoc-lib/src/");
         println!("Expected directories like: aoc-lib/src/year2024_old/");
         return Ok(());
     }
-    
-    println!("Found {} year(s): {}", 
+
+    println!(
+        "Found {} year(s): {}",
         years.len(),
-        years.iter().map(|y| y.to_string()).collect::<Vec<_>>().join(", ")
+        years
+            .iter()
+            .map(|y| y.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
     );
-    
+
+    // The generated day_count reads each year's DAY_COUNT; check them all
+    // before writing anything
+    for &year in &years {
+        require_day_count(year)?;
+    }
+
+    // Upgrade DAYS entries written before puzzle titles were registered
+    for &year in &years {
+        upgrade_legacy_entries(year)?;
+    }
+
     // Update lib.rs
     update_lib_rs(&years)?;
-    
+
     // Update registry_generated.rs
     update_registry_generated(&years)?;
-    
+
+    // Update the generated regression tests
+    update_regression_tests(&years)?;
+
     println!("Registry regeneration complete");
-    
+
     Ok(())
 }
 
 // Scan aoc-lib/src for directories matching yearYYYY pattern
 fn scan_years() -> Result<Vec<u16>> {
     let src_dir = PathBuf::from("aoc-lib/src");
-    
+
     if !src_dir.exists() {
         anyhow::bail!("aoc-lib/src directory not found. Run this from the workspace root.");
     }
-    
+
     let mut years = Vec::new();
-    
+
     for entry in fs::read_dir(&src_dir)
-        .with_context(|| format!("failed to read {}", src_dir.display()))? 
+        .with_context(|| format!("failed to read {}", src_dir.display()))?
     {
         let entry = entry?;
         let path = entry.path();
-        
+
         if !path.is_dir() {
             continue;
         }
-        
+
         if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
             if name.starts_with("year") && name.len() == 8 {
                 if let Ok(year) = name[4..].parse::<u16>() {
@@ -64,47 +86,196 @@ fn scan_years() -> Result<Vec<u16>> {
             }
         }
     }
-    
+
     years.sort();
     Ok(years)
 }
 
+// Scan aoc-lib/src/yearYYYY/mod.rs for registered `mod dayDD;` lines
+fn scan_days(year: u16) -> Result<Vec<u8>> {
+    let mod_path = PathBuf::from(format!("aoc-lib/src/year{}/mod.rs", year));
+    let content = fs::read_to_string(&mod_path)
+        .with_context(|| format!("failed to read {}", mod_path.display()))?;
+
+    let mut days: Vec<u8> = content
+        .lines()
+        .filter_map(|line| {
+            line.trim()
+                .strip_prefix("mod day")?
+                .strip_suffix(';')?
+                .parse::<u8>()
+                .ok()
+        })
+        .collect();
+
+    days.sort();
+    Ok(days)
+}
+
+// Rewrite old `("D", dayDD::part1, dayDD::part2),` entries in a year's
+// mod.rs to `("D", dayDD::TITLE, dayDD::part1, dayDD::part2),`, giving
+// each such day file an empty `TITLE` if it has none. aoc-lib does not
+// compile until this is done, so it lives here rather than in new-day.
+fn upgrade_legacy_entries(year: u16) -> Result<()> {
+    let mod_path = PathBuf::from(format!("aoc-lib/src/year{}/mod.rs", year));
+    let content = fs::read_to_string(&mod_path)
+        .with_context(|| format!("failed to read {}", mod_path.display()))?;
+
+    let mut upgraded = Vec::new();
+    let lines: Vec<String> = content
+        .lines()
+        .map(|line| match legacy_entry_day(line) {
+            Some(day) => {
+                upgraded.push(day);
+                format!(
+                    "    (\"{}\", day{:02}::TITLE, day{:02}::part1, day{:02}::part2),",
+                    day, day, day, day
+                )
+            }
+            None => line.to_string(),
+        })
+        .collect();
+
+    if upgraded.is_empty() {
+        return Ok(());
+    }
+
+    for &day in &upgraded {
+        add_title_const(year, day)?;
+    }
+    fs::write(&mod_path, lines.join("\n") + "\n")
+        .with_context(|| format!("failed to write {}", mod_path.display()))?;
+
+    println!(
+        "Upgraded {} DAYS entr{} in {} to include TITLE",
+        upgraded.len(),
+        if upgraded.len() == 1 { "y" } else { "ies" },
+        mod_path.display()
+    );
+
+    Ok(())
+}
+
+// Fail unless a year's mod.rs declares `pub const DAY_COUNT`. new-day writes
+// it when it creates the year; event lengths are not guessed here.
+fn require_day_count(year: u16) -> Result<()> {
+    let mod_path = PathBuf::from(format!("aoc-lib/src/year{}/mod.rs", year));
+    let content = fs::read_to_string(&mod_path)
+        .with_context(|| format!("failed to read {}", mod_path.display()))?;
+    if content
+        .lines()
+        .any(|line| line.trim().starts_with("pub const DAY_COUNT"))
+    {
+        return Ok(());
+    }
+
+    anyhow::bail!(
+        "{} has no DAY_COUNT. Add `pub const DAY_COUNT: u8 = N;` with the number of puzzles in the {} event",
+        mod_path.display(),
+        year
+    )
+}
+
+// The day of an old three-field entry: ("5", day05::part1, day05::part2),
+fn legacy_entry_day(line: &str) -> Option<u8> {
+    let day: u8 = line
+        .trim()
+        .strip_prefix("(\"")?
+        .split('"')
+        .next()?
+        .parse()
+        .ok()?;
+    let legacy =
+        format!("(\"{}\", day{:02}::part1, day{:02}::part2),", day, day, day);
+    (line.trim() == legacy).then_some(day)
+}
+
+// The canonical `yearYYYY/dayDD` form of aoc_lib::PuzzleId. This tool
+// must run while aoc-lib does not compile, so it cannot link the type.
+fn puzzle_label(year: u16, day: u8) -> String {
+    format!("year{}/day{:02}", year, day)
+}
+
+// Give a day file an empty `TITLE` after its `use` lines if it has none
+fn add_title_const(year: u16, day: u8) -> Result<()> {
+    let day_path =
+        PathBuf::from(format!("aoc-lib/src/{}.rs", puzzle_label(year, day)));
+    let content = fs::read_to_string(&day_path)
+        .with_context(|| format!("failed to read {}", day_path.display()))?;
+    if content.contains("pub const TITLE") {
+        return Ok(());
+    }
+
+    let mut lines: Vec<String> =
+        content.lines().map(|s| s.to_string()).collect();
+    let insert_idx = lines
+        .iter()
+        .rposition(|line| line.starts_with("use "))
+        .map(|i| i + 1)
+        .unwrap_or(0);
+    lines.insert(insert_idx, String::new());
+    lines.insert(
+        insert_idx + 1,
+        "/// Puzzle title, shown by `aoc list` and searched by `aoc find`"
+            .to_string(),
+    );
+    lines.insert(insert_idx + 2, "pub const TITLE: &str = \"\";".to_string());
+
+    fs::write(&day_path, lines.join("\n") + "\n")
+        .with_context(|| format!("failed to write {}", day_path.display()))?;
+
+    Ok(())
+}
+
 // Update aoc-lib/src/lib.rs to match detected years
 fn update_lib_rs(years: &[u16]) -> Result<()> {
     let lib_path = PathBuf::from("aoc-lib/src/lib.rs");
-    
+
     if !lib_path.exists() {
         anyhow::bail!("aoc-lib/src/lib.rs not found");
     }
-    
+
     let content = fs::read_to_string(&lib_path)
         .with_context(|| format!("failed to read {}", lib_path.display()))?;
-    
-    let mut lines: Vec<String> = content.lines().map(|s| s.to_string()).collect();
-    
-    // Remove all existing "pub mod yearXXXX;" lines
-    lines.retain(|line| {
+
+    // Remove all existing "pub mod yearXXXX;" lines, with the attribute
+    // that keeps rustfmt out of the generated year modules
+    let mut lines: Vec<String> = Vec::new();
+    for line in content.lines() {
         let trimmed = line.trim();
-        !(trimmed.starts_with("pub mod year") && trimmed.ends_with(';'))
-    });
-    
+        if trimmed.starts_with("pub mod year") && trimmed.ends_with(';') {
+            if lines.last().is_some_and(|prev| prev.trim() == RUSTFMT_SKIP) {
+                lines.pop();
+            }
+            continue;
+        }
+        lines.push(line.to_string());
+    }
+
     // Find where to insert year modules (after "pub mod utils;")
-    let insert_idx = lines.iter().position(|line| line.trim() == "pub mod utils;")
+    let insert_idx = lines
+        .iter()
+        .position(|line| line.trim() == "pub mod utils;")
         .map(|i| i + 1)
         .unwrap_or(0);
-    
-    // Insert all detected years
+
+    // Insert all detected years. new-day edits their mod.rs line by line,
+    // so rustfmt must leave them as generated.
     for (i, year) in years.iter().enumerate() {
-        lines.insert(insert_idx + i, format!("pub mod year{};", year));
+        lines.insert(insert_idx + 2 * i, RUSTFMT_SKIP.to_string());
+        lines.insert(insert_idx + 2 * i + 1, format!("pub mod year{};", year));
     }
-    
+
     let updated = lines.join("\n") + "\n";
-    
+
     fs::write(&lib_path, updated)
         .with_context(|| format!("failed to write {}", lib_path.display()))?;
-    
-    println!("Updated aoc-lib/src/lib.rs with {} year module(s)", years.len());
-    
+
+    println!(
+        "Updated aoc-lib/src/lib.rs with {} year module(s)",
+        years.len()
+    );
+
     Ok(())
 }
 
@@ -112,72 +283,197 @@ fn update_lib_rs(years: &[u16]) -> Result<()> {
 fn update_registry_generated(years: &[u16]) -> Result<()> {
     let registry_content = build_registry_file(years);
     let registry_path = PathBuf::from("aoc-lib/src/registry_generated.rs");
-    
-    fs::write(&registry_path, registry_content)
-        .with_context(|| format!("failed to write {}", registry_path.display()))?;
-    
+
+    fs::write(&registry_path, registry_content).with_context(|| {
+        format!("failed to write {}", registry_path.display())
+    })?;
+
     println!("Updated aoc-lib/src/registry_generated.rs");
-    
+
     Ok(())
 }
 
+// Update aoc-lib/tests/regression_generated.rs
+fn update_regression_tests(years: &[u16]) -> Result<()> {
+    let mut year_days = Vec::new();
+    for &year in years {
+        year_days.push((year, scan_days(year)?));
+    }
+
+    let tests_content = build_regression_tests_file(&year_days);
+    let tests_path = PathBuf::from("aoc-lib/tests/regression_generated.rs");
+
+    if let Some(parent) = tests_path.parent() {
+        fs::create_dir_all(parent).with_context(|| {
+            format!("failed to create {}", parent.display())
+        })?;
+    }
+    fs::write(&tests_path, tests_content)
+        .with_context(|| format!("failed to write {}", tests_path.display()))?;
+
+    println!("Updated aoc-lib/tests/regression_generated.rs");
+
+    Ok(())
+}
+
+// Build one #[test] per registered year/day, checked against answers/yearYYYY.toml
+fn build_regression_tests_file(year_days: &[(u16, Vec<u8>)]) -> String {
+    let mut output = String::new();
+
+    output.push_str("// AUTO-GENERATED - DO NOT EDIT MANUALLY\n");
+    output.push_str("// Regenerate with: cargo run --bin registry-tool\n");
+    output.push_str("//\n");
+    output.push_str("// One test per registered day, checked against answers/yearYYYY.toml.\n");
+    output.push_str(
+        "// Days without an input file or recorded answers are skipped.\n\n",
+    );
+
+    output.push_str("use aoc_lib::PuzzleId;\n\n");
+    output.push_str("fn check(puzzle: &str) {\n");
+    output.push_str(
+        "    // Also fails if a registered day is past the year's DAY_COUNT\n",
+    );
+    output.push_str("    let id: PuzzleId = puzzle.parse().unwrap();\n");
+    output.push_str("    // Inputs and answers resolve from the workspace root, whatever the cwd\n");
+    output.push_str("    aoc_lib::utils::answers::assert_recorded_answers(id.year(), id.day());\n");
+    output.push_str("}\n");
+
+    for (year, days) in year_days {
+        for &day in days {
+            let puzzle = puzzle_label(*year, day);
+            output.push_str("\n#[test]\n");
+            output.push_str(&format!("fn {}() {{\n", puzzle.replace('/', "_")));
+            output.push_str(&format!("    check(\"{}\");\n", puzzle));
+            output.push_str("}\n");
+        }
+    }
+
+    output
+}
+
 // Build the complete registry_generated.rs file content
 fn build_registry_file(years: &[u16]) -> String {
     let mut output = String::new();
-    
+
     output.push_str("// AUTO-GENERATED - DO NOT EDIT MANUALLY\n");
     output.push_str("// Regenerate with: cargo run --bin registry-tool\n\n");
-    output.push_str("use anyhow::Result;\n\n");
-    
+    output.push_str(
+        "use crate::solution::{DayEntry, DaySolver, PartSolver};\n\n",
+    );
+
     output.push_str("// Import all detected year modules\n");
     for year in years {
         output.push_str(&format!("use crate::year{};\n", year));
     }
     output.push('\n');
-    
-    output.push_str("// Type alias for day registry entries\n");
-    output.push_str("type DayEntry = (&'static str, fn() -> Result<()>);\n\n");
-    
+
     output.push_str("pub struct SolutionRegistry;\n\n");
-    
-    output.push_str("// Helper: convert DAYS entries like (\"01\", solver) to Vec<u8>\n");
+
+    output.push_str("// Helper: convert DAYS entries like (\"01\", TITLE, part1, part2) to Vec<u8>\n");
     output.push_str("fn days_to_u8(days: &[DayEntry]) -> Vec<u8> {\n");
-    output.push_str("    days.iter().filter_map(|(d, _)| d.parse::<u8>().ok()).collect()\n");
+    output.push_str("    days.iter().filter_map(|(d, _, _, _)| d.parse::<u8>().ok()).collect()\n");
     output.push_str("}\n\n");
-    
-    output.push_str("// Helper: find solver for a given day in a year's DAYS\n");
-    output.push_str("fn find_solver(days: &[DayEntry], day: u8) -> Option<fn() -> Result<()>> {\n");
+
+    output.push_str(
+        "// Helper: find both part solvers for a given day in a year's DAYS\n",
+    );
+    output.push_str(
+        "fn find_solver(days: &[DayEntry], day: u8) -> Option<DaySolver> {\n",
+    );
     output.push_str("    let day_str = day.to_string();\n");
-    output.push_str("    days.iter().find(|(d, _)| *d == day_str).map(|(_, s)| *s)\n");
+    output.push_str("    days.iter()\n");
+    output.push_str("        .find(|(d, _, _, _)| *d == day_str)\n");
+    output.push_str("        .map(|(_, _, part1, part2)| DaySolver { part1: *part1, part2: *part2 })\n");
     output.push_str("}\n\n");
-    
+
+    output.push_str(
+        "// Helper: find the puzzle title for a given day in a year's DAYS\n",
+    );
+    output.push_str(
+        "fn find_title(days: &[DayEntry], day: u8) -> Option<&'static str> {\n",
+    );
+    output.push_str("    let day_str = day.to_string();\n");
+    output.push_str("    days.iter()\n");
+    output.push_str("        .find(|(d, _, _, _)| *d == day_str)\n");
+    output.push_str("        .map(|(_, title, _, _)| *title)\n");
+    output.push_str("        .filter(|title| !title.is_empty())\n");
+    output.push_str("}\n\n");
+
     output.push_str("impl SolutionRegistry {\n");
-    
-    output.push_str("    pub fn get_solver(year: u16, day: u8) -> Option<fn() -> Result<()>> {\n");
+
+    output.push_str(
+        "    pub fn get_solver(year: u16, day: u8) -> Option<DaySolver> {\n",
+    );
     output.push_str("        match year {\n");
     for year in years {
-        output.push_str(&format!("            {} => find_solver(&year{}::DAYS, day),\n", year, year));
+        output.push_str(&format!(
+            "            {} => find_solver(year{}::DAYS, day),\n",
+            year, year
+        ));
     }
     output.push_str("            _ => None,\n");
     output.push_str("        }\n");
     output.push_str("    }\n\n");
-    
+
+    output.push_str("    pub fn get_part(year: u16, day: u8, part: u8) -> Option<PartSolver> {\n");
+    output.push_str("        Self::get_solver(year, day)?.part(part)\n");
+    output.push_str("    }\n\n");
+
+    output.push_str("    /// Puzzle title from the day's TITLE; None if unregistered or untitled\n");
+    output.push_str(
+        "    pub fn title(year: u16, day: u8) -> Option<&'static str> {\n",
+    );
+    output.push_str("        match year {\n");
+    for year in years {
+        output.push_str(&format!(
+            "            {} => find_title(year{}::DAYS, day),\n",
+            year, year
+        ));
+    }
+    output.push_str("            _ => None,\n");
+    output.push_str("        }\n");
+    output.push_str("    }\n\n");
+
+    output.push_str("    /// Puzzles in a year's event: its DAY_COUNT, else the schedule's default\n");
+    output.push_str("    pub fn day_count(year: u16) -> u8 {\n");
+    output.push_str("        match year {\n");
+    for year in years {
+        output.push_str(&format!(
+            "            {} => year{}::DAY_COUNT,\n",
+            year, year
+        ));
+    }
+    output.push_str(
+        "            _ => crate::utils::calendar::default_day_count(year),\n",
+    );
+    output.push_str("        }\n");
+    output.push_str("    }\n\n");
+
     output.push_str("    pub fn available_years() -> Vec<u16> {\n");
     output.push_str("        vec![");
-    output.push_str(&years.iter().map(|y| y.to_string()).collect::<Vec<_>>().join(", "));
+    output.push_str(
+        &years
+            .iter()
+            .map(|y| y.to_string())
+            .collect::<Vec<_>>()
+            .join(", "),
+    );
     output.push_str("]\n");
     output.push_str("    }\n\n");
-    
+
     output.push_str("    pub fn available_days(year: u16) -> Vec<u8> {\n");
     output.push_str("        match year {\n");
     for year in years {
-        output.push_str(&format!("            {} => days_to_u8(&year{}::DAYS),\n", year, year));
+        output.push_str(&format!(
+            "            {} => days_to_u8(year{}::DAYS),\n",
+            year, year
+        ));
     }
     output.push_str("            _ => vec![],\n");
     output.push_str("        }\n");
     output.push_str("    }\n");
-    
+
     output.push_str("}\n");
-    
+
     output
 }